@@ -9,5 +9,36 @@ fn path_large(bencher: &mut Bencher) {
     })
 }
 
+fn number_list(bencher: &mut Bencher) {
+    let text = "10 -20.5 0.125 300 -.75 1e2 42.42 7 ".repeat(1000);
+    bencher.iter(|| {
+        for n in svgtypes::NumberListParser::from(text.as_str()) {
+            let _ = n.unwrap();
+        }
+    })
+}
+
+fn number_list_std(bencher: &mut Bencher) {
+    let text = "10 -20.5 0.125 300 -.75 1e2 42.42 7 ".repeat(1000);
+    bencher.iter(|| {
+        for n in text.split_whitespace() {
+            let _ = n.parse::<f64>().unwrap();
+        }
+    })
+}
+
+fn transform_append(bencher: &mut Bencher) {
+    let other = svgtypes::Transform::new(1.0, 0.001, -0.001, 1.0, 0.5, -0.5);
+    bencher.iter(|| {
+        let mut ts = svgtypes::Transform::default();
+        for _ in 0..10_000 {
+            ts.append(&other);
+        }
+        bencher::black_box(ts)
+    })
+}
+
 benchmark_group!(paths, path_large);
-benchmark_main!(paths);
+benchmark_group!(numbers, number_list, number_list_std);
+benchmark_group!(transforms, transform_append);
+benchmark_main!(paths, numbers, transforms);
@@ -97,6 +97,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     writeln!(f, "    {}.get(text).cloned()", map_name)?;
     writeln!(f, "}}")?;
     writeln!(f, "")?;
+
+    writeln!(f, "pub fn all() -> impl Iterator<Item = (&'static str, {})> {{", struct_name)?;
+    writeln!(f, "    {}.entries.iter().cloned()", map_name)?;
+    writeln!(f, "}}")?;
+    writeln!(f, "")?;
     writeln!(f, "{}", PHF_SRC)?;
 
     Ok(())
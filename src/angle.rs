@@ -2,6 +2,7 @@ use crate::{Error, Stream};
 
 /// List of all SVG angle units.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum AngleUnit {
     Degrees,
@@ -14,6 +15,7 @@ pub enum AngleUnit {
 ///
 /// [`<angle>`]: https://www.w3.org/TR/css-values-3/#angles
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub struct Angle {
     pub number: f64,
@@ -37,6 +39,45 @@ impl Angle {
             AngleUnit::Turns => self.number * 360.0,
         }
     }
+
+    /// Converts angle to radians.
+    #[inline]
+    pub fn to_radians(&self) -> f64 {
+        match self.unit {
+            AngleUnit::Radians => self.number,
+            _ => self.to_degrees().to_radians(),
+        }
+    }
+
+    /// Converts angle to turns.
+    #[inline]
+    pub fn to_turns(&self) -> f64 {
+        match self.unit {
+            AngleUnit::Turns => self.number,
+            _ => self.to_degrees() / 360.0,
+        }
+    }
+
+    /// Returns an angle reduced into a single full turn, keeping the unit.
+    ///
+    /// For example, `370deg` becomes `10deg` and `-90deg` becomes `270deg`.
+    #[inline]
+    pub fn normalized(&self) -> Angle {
+        let full_turn = match self.unit {
+            AngleUnit::Degrees => 360.0,
+            AngleUnit::Gradians => 400.0,
+            AngleUnit::Radians => std::f64::consts::PI * 2.0,
+            AngleUnit::Turns => 1.0,
+        };
+
+        let mut number = self.number.rem_euclid(full_turn);
+        // Tiny negative values are rounded up to a full turn.
+        if number == full_turn {
+            number = 0.0;
+        }
+
+        Angle::new(number, self.unit)
+    }
 }
 
 impl std::str::FromStr for Angle {
@@ -63,6 +104,19 @@ impl<'a> Stream<'a> {
     /// # Notes
     ///
     /// - Suffix must be lowercase, otherwise it will be an error.
+    /// - A number without a suffix is in degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgtypes::{Angle, AngleUnit, Stream};
+    ///
+    /// let mut s = Stream::from("45deg 1rad 90");
+    /// assert_eq!(s.parse_angle().unwrap(), Angle::new(45.0, AngleUnit::Degrees));
+    /// assert_eq!(s.parse_angle().unwrap(), Angle::new(1.0, AngleUnit::Radians));
+    /// assert_eq!(s.parse_angle().unwrap(), Angle::new(90.0, AngleUnit::Degrees));
+    /// assert!(s.at_end());
+    /// ```
     pub fn parse_angle(&mut self) -> Result<Angle, Error> {
         self.skip_spaces();
 
@@ -90,6 +144,60 @@ impl<'a> Stream<'a> {
 
         Ok(Angle::new(n, u))
     }
+
+    /// Parses angle from a list of angles.
+    pub fn parse_list_angle(&mut self) -> Result<Angle, Error> {
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
+
+        let a = self.parse_angle()?;
+        self.skip_spaces();
+        self.parse_list_separator();
+        Ok(a)
+    }
+}
+
+/// A pull-based list of [`<angle>`] parser.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::{Angle, AngleUnit, AngleListParser};
+///
+/// let mut p = AngleListParser::from("10deg 0.5turn 1rad");
+/// assert_eq!(p.next().unwrap().unwrap(), Angle::new(10.0, AngleUnit::Degrees));
+/// assert_eq!(p.next().unwrap().unwrap(), Angle::new(0.5, AngleUnit::Turns));
+/// assert_eq!(p.next().unwrap().unwrap(), Angle::new(1.0, AngleUnit::Radians));
+/// assert_eq!(p.next().is_none(), true);
+/// ```
+///
+/// [`<angle>`]: https://www.w3.org/TR/css-values-3/#angles
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AngleListParser<'a>(Stream<'a>);
+
+impl<'a> From<&'a str> for AngleListParser<'a> {
+    #[inline]
+    fn from(v: &'a str) -> Self {
+        AngleListParser(Stream::from(v))
+    }
+}
+
+impl<'a> Iterator for AngleListParser<'a> {
+    type Item = Result<Angle, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0.at_end() {
+            None
+        } else {
+            let v = self.0.parse_list_angle();
+            if v.is_err() {
+                self.0.jump_to_end();
+            }
+
+            Some(v)
+        }
+    }
 }
 
 #[rustfmt::skip]
@@ -112,6 +220,53 @@ mod tests {
     test_p!(parse_3,  "1grad", Angle::new(1.0, AngleUnit::Gradians));
     test_p!(parse_4,  "1rad", Angle::new(1.0, AngleUnit::Radians));
     test_p!(parse_5,  "1turn", Angle::new(1.0, AngleUnit::Turns));
+    test_p!(parse_signed_1, "+1deg", Angle::new(1.0, AngleUnit::Degrees));
+    test_p!(parse_signed_2, "-1deg", Angle::new(-1.0, AngleUnit::Degrees));
+    test_p!(parse_signed_3, "+0.5turn", Angle::new(0.5, AngleUnit::Turns));
+    test_p!(parse_signed_4, "-2rad", Angle::new(-2.0, AngleUnit::Radians));
+    test_p!(parse_signed_5, "+90", Angle::new(90.0, AngleUnit::Degrees));
+
+    #[test]
+    fn list_1() {
+        let mut p = AngleListParser::from(" 10, -20grad,0.5turn ");
+        assert_eq!(p.next().unwrap().unwrap(), Angle::new(10.0, AngleUnit::Degrees));
+        assert_eq!(p.next().unwrap().unwrap(), Angle::new(-20.0, AngleUnit::Gradians));
+        assert_eq!(p.next().unwrap().unwrap(), Angle::new(0.5, AngleUnit::Turns));
+        assert!(p.next().is_none());
+    }
+
+    #[test]
+    fn list_err_1() {
+        let mut p = AngleListParser::from("10deg q 20deg");
+        assert_eq!(p.next().unwrap().unwrap(), Angle::new(10.0, AngleUnit::Degrees));
+        assert_eq!(p.next().unwrap().unwrap_err().to_string(), "invalid number at position 7");
+        assert!(p.next().is_none());
+    }
+
+    #[test]
+    fn normalized_1() {
+        assert_eq!(Angle::new(370.0, AngleUnit::Degrees).normalized(), Angle::new(10.0, AngleUnit::Degrees));
+        assert_eq!(Angle::new(-90.0, AngleUnit::Degrees).normalized(), Angle::new(270.0, AngleUnit::Degrees));
+        assert_eq!(Angle::new(360.0, AngleUnit::Degrees).normalized(), Angle::new(0.0, AngleUnit::Degrees));
+        assert_eq!(Angle::new(450.0, AngleUnit::Gradians).normalized(), Angle::new(50.0, AngleUnit::Gradians));
+        assert_eq!(Angle::new(-1.25, AngleUnit::Turns).normalized(), Angle::new(0.75, AngleUnit::Turns));
+        assert_eq!(Angle::new(-1e-20, AngleUnit::Degrees).normalized(), Angle::new(0.0, AngleUnit::Degrees));
+        assert_eq!(Angle::new(-1e-20, AngleUnit::Radians).normalized(), Angle::new(0.0, AngleUnit::Radians));
+    }
+
+    #[test]
+    fn to_radians_1() {
+        assert_eq!(Angle::new(180.0, AngleUnit::Degrees).to_radians(), std::f64::consts::PI);
+        assert_eq!(Angle::new(2.0, AngleUnit::Radians).to_radians(), 2.0);
+        assert_eq!(Angle::new(0.5, AngleUnit::Turns).to_radians(), std::f64::consts::PI);
+    }
+
+    #[test]
+    fn to_turns_1() {
+        assert_eq!(Angle::new(90.0, AngleUnit::Degrees).to_turns(), 0.25);
+        assert_eq!(Angle::new(200.0, AngleUnit::Gradians).to_turns(), 0.5);
+        assert_eq!(Angle::new(3.0, AngleUnit::Turns).to_turns(), 3.0);
+    }
 
     #[test]
     fn err_1() {
@@ -126,4 +281,52 @@ mod tests {
         assert_eq!(Angle::from_str("1degq").unwrap_err().to_string(),
                    "unexpected data at position 5");
     }
+
+    macro_rules! test_err {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(Angle::from_str($text).unwrap_err().to_string(), $result);
+            }
+        )
+    }
+
+    test_err!(err_partial_suffix_1, "1g", "unexpected data at position 2");
+    test_err!(err_partial_suffix_2, "1gra", "unexpected data at position 2");
+    test_err!(err_partial_suffix_3, "1ra", "unexpected data at position 2");
+    test_err!(err_partial_suffix_4, "1tur", "unexpected data at position 2");
+    test_err!(err_partial_suffix_5, "1de", "unexpected data at position 2");
+    test_err!(err_suffix_case_1, "1DEG", "unexpected data at position 2");
+
+    #[test]
+    fn suffix_stream_1() {
+        let mut s = Stream::from("1grad 2turn");
+        assert_eq!(s.parse_angle().unwrap(), Angle::new(1.0, AngleUnit::Gradians));
+        assert_eq!(s.pos(), 5);
+        assert_eq!(s.parse_angle().unwrap(), Angle::new(2.0, AngleUnit::Turns));
+        assert!(s.at_end());
+    }
+
+    #[test]
+    fn list_stream_1() {
+        let mut s = Stream::from("45deg,90deg");
+        assert_eq!(s.parse_list_angle().unwrap(), Angle::new(45.0, AngleUnit::Degrees));
+        assert_eq!(s.parse_list_angle().unwrap(), Angle::new(90.0, AngleUnit::Degrees));
+        assert!(s.at_end());
+    }
+
+    #[test]
+    fn suffix_stream_2() {
+        let mut s = Stream::from("1g");
+        assert_eq!(s.parse_angle().unwrap(), Angle::new(1.0, AngleUnit::Degrees));
+        assert_eq!(s.pos(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let a = Angle::new(0.5, AngleUnit::Turns);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(serde_json::from_str::<Angle>(&json).unwrap(), a);
+    }
 }
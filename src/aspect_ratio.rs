@@ -1,10 +1,11 @@
-use crate::{Error, Stream};
+use crate::{Error, Stream, Transform, ViewBox};
 
 /// Representation of the `align` value of the [`preserveAspectRatio`] attribute.
 ///
 /// [`preserveAspectRatio`]: https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     None,
     XMinYMin,
@@ -24,6 +25,7 @@ pub enum Align {
 ///
 /// [`preserveAspectRatio`]: https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AspectRatio {
     /// `<defer>` value.
     ///
@@ -91,6 +93,46 @@ impl std::str::FromStr for AspectRatio {
     }
 }
 
+impl AspectRatio {
+    /// Returns a transform that maps the `view_box` onto a viewport of the specified size.
+    ///
+    /// Implements the [equivalent transform] algorithm from the SVG 2 spec.
+    ///
+    /// [equivalent transform]: https://www.w3.org/TR/SVG2/coords.html#ComputingAViewportsTransform
+    pub fn view_box_transform(&self, view_box: ViewBox, viewport: (f64, f64)) -> Transform {
+        let (width, height) = viewport;
+
+        let mut sx = width / view_box.w;
+        let mut sy = height / view_box.h;
+
+        if self.align != Align::None {
+            let s = if self.slice { sx.max(sy) } else { sx.min(sy) };
+            sx = s;
+            sy = s;
+        }
+
+        let mut tx = -view_box.x * sx;
+        let mut ty = -view_box.y * sy;
+
+        let free_x = width - view_box.w * sx;
+        let free_y = height - view_box.h * sy;
+
+        match self.align {
+            Align::XMidYMin | Align::XMidYMid | Align::XMidYMax => tx += free_x / 2.0,
+            Align::XMaxYMin | Align::XMaxYMid | Align::XMaxYMax => tx += free_x,
+            _ => {}
+        }
+
+        match self.align {
+            Align::XMinYMid | Align::XMidYMid | Align::XMaxYMid => ty += free_y / 2.0,
+            Align::XMinYMax | Align::XMidYMax | Align::XMaxYMax => ty += free_y,
+            _ => {}
+        }
+
+        Transform::new(sx, 0.0, 0.0, sy, tx, ty)
+    }
+}
+
 impl Default for AspectRatio {
     #[inline]
     fn default() -> Self {
@@ -147,4 +189,41 @@ mod tests {
         align: Align::XMinYMid,
         slice: false,
     });
+
+    macro_rules! test_ts {
+        ($name:ident, $aspect:expr, $view_box:expr, $size:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                let aspect = AspectRatio::from_str($aspect).unwrap();
+                let view_box = ViewBox::from_str($view_box).unwrap();
+                assert_eq!(aspect.view_box_transform(view_box, $size), $result);
+            }
+        )
+    }
+
+    test_ts!(view_box_transform_1, "xMidYMid meet", "0 0 100 100", (300.0, 100.0),
+             Transform::new(1.0, 0.0, 0.0, 1.0, 100.0, 0.0));
+
+    test_ts!(view_box_transform_2, "xMidYMid meet", "0 0 100 50", (200.0, 200.0),
+             Transform::new(2.0, 0.0, 0.0, 2.0, 0.0, 50.0));
+
+    test_ts!(view_box_transform_3, "xMinYMax slice", "0 0 100 100", (300.0, 100.0),
+             Transform::new(3.0, 0.0, 0.0, 3.0, 0.0, -200.0));
+
+    test_ts!(view_box_transform_4, "xMaxYMid meet", "0 0 100 100", (300.0, 100.0),
+             Transform::new(1.0, 0.0, 0.0, 1.0, 200.0, 0.0));
+
+    test_ts!(view_box_transform_5, "none", "10 20 100 100", (200.0, 300.0),
+             Transform::new(2.0, 0.0, 0.0, 3.0, -20.0, -60.0));
+
+    test_ts!(view_box_transform_6, "xMinYMin meet", "10 20 100 100", (200.0, 300.0),
+             Transform::new(2.0, 0.0, 0.0, 2.0, -20.0, -40.0));
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let ratio = AspectRatio::from_str("defer xMidYMax slice").unwrap();
+        let json = serde_json::to_string(&ratio).unwrap();
+        assert_eq!(serde_json::from_str::<AspectRatio>(&json).unwrap(), ratio);
+    }
 }
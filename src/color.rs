@@ -4,6 +4,7 @@ use crate::{colors, ByteExt, Error, Stream};
 ///
 /// [`<color>`]: https://www.w3.org/TR/css-color-3/
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub struct Color {
     pub red: u8,
@@ -70,6 +71,70 @@ impl Color {
     pub fn blue() -> Color {
         Color::new_rgb(0, 0, 255)
     }
+
+    /// Linearly interpolates between two colors in the sRGB space.
+    ///
+    /// `t` is clamped to the `0..=1` range.
+    ///
+    /// This is how most browsers interpolate colors, e.g. in gradients.
+    /// Use [`lerp_linear()`](#method.lerp_linear) for a physically correct result.
+    pub fn lerp(&self, other: Color, t: f64) -> Color {
+        let t = f64_bound(0.0, t, 1.0);
+        let mix = |a: u8, b: u8| to_u8(a as f64 / 255.0 + (b as f64 - a as f64) / 255.0 * t);
+        Color::new_rgba(
+            mix(self.red, other.red),
+            mix(self.green, other.green),
+            mix(self.blue, other.blue),
+            mix(self.alpha, other.alpha),
+        )
+    }
+
+    /// Linearly interpolates between two colors in the linear-light space.
+    ///
+    /// `t` is clamped to the `0..=1` range.
+    ///
+    /// Color channels are gamma-decoded before interpolation and encoded back afterwards,
+    /// which matches how light mixes physically. Alpha is interpolated as is.
+    pub fn lerp_linear(&self, other: Color, t: f64) -> Color {
+        let t = f64_bound(0.0, t, 1.0);
+        let mix = |a: u8, b: u8| {
+            let a = srgb_to_linear(a);
+            let b = srgb_to_linear(b);
+            linear_to_srgb(a + (b - a) * t)
+        };
+
+        Color::new_rgba(
+            mix(self.red, other.red),
+            mix(self.green, other.green),
+            mix(self.blue, other.blue),
+            to_u8(self.alpha as f64 / 255.0 + (other.alpha as f64 - self.alpha as f64) / 255.0 * t),
+        )
+    }
+
+    /// Composites the current color over the `background` using the source-over operator.
+    ///
+    /// Blending is done in the sRGB space, like browsers do.
+    pub fn blend_over(&self, background: Color) -> Color {
+        blend_over(self, &background, |c| c as f64 / 255.0, to_u8)
+    }
+
+    /// Composites the current color over the `background` using the source-over operator.
+    ///
+    /// Same as [`blend_over()`](#method.blend_over), but blending is done
+    /// in the linear-light space.
+    pub fn blend_over_linear(&self, background: Color) -> Color {
+        blend_over(self, &background, srgb_to_linear, linear_to_srgb)
+    }
+
+    /// Returns all [named colors] and their values, including `transparent`.
+    ///
+    /// The order is unspecified.
+    ///
+    /// [named colors]: https://www.w3.org/TR/css-color-3/#svg-color
+    #[inline]
+    pub fn all_named() -> impl Iterator<Item = (&'static str, Color)> {
+        colors::all()
+    }
 }
 
 impl std::str::FromStr for Color {
@@ -121,9 +186,15 @@ impl<'a> Stream<'a> {
     }
 
     /// Parses a color.
+    ///
+    /// # Errors
+    ///
+    /// An invalid hex notation or an unknown color name will produce
+    /// `UnexpectedData` pointing to the start of the color.
     pub fn parse_color(&mut self) -> Result<Color, Error> {
         self.skip_spaces();
 
+        let start = self.pos();
         let mut color = Color::black();
 
         if self.curr_byte()? == b'#' {
@@ -159,7 +230,7 @@ impl<'a> Stream<'a> {
                     color.alpha = short_hex(color_str[3]);
                 }
                 _ => {
-                    return Err(Error::InvalidValue);
+                    return Err(Error::UnexpectedData(self.calc_char_pos_at(start)));
                 }
             }
         } else {
@@ -187,7 +258,7 @@ impl<'a> Stream<'a> {
                     color.green = from_percent(self.parse_list_number_or_percent()?);
                     color.blue = from_percent(self.parse_list_number_or_percent()?);
                 } else {
-                    color.red = f64_bound(0.0, (value.round() as i32).into(), 255.0) as u8;
+                    color.red = f64_bound(0.0, value.round(), 255.0) as u8;
                     color.green = f64_bound(0.0, self.parse_list_number()?.round(), 255.0) as u8;
                     color.blue = f64_bound(0.0, self.parse_list_number()?.round(), 255.0) as u8;
                 }
@@ -223,7 +294,7 @@ impl<'a> Stream<'a> {
                         color = c;
                     }
                     None => {
-                        return Err(Error::InvalidValue);
+                        return Err(Error::UnexpectedData(self.calc_char_pos_at(start)));
                     }
                 }
             }
@@ -295,6 +366,53 @@ fn hue_to_rgb(t1: f32, t2: f32, mut hue: f32) -> f32 {
     }
 }
 
+#[inline]
+fn blend_over(
+    fg: &Color,
+    bg: &Color,
+    decode: impl Fn(u8) -> f64,
+    encode: impl Fn(f64) -> u8,
+) -> Color {
+    let fg_a = fg.alpha as f64 / 255.0;
+    let bg_a = bg.alpha as f64 / 255.0;
+    let a = fg_a + bg_a * (1.0 - fg_a);
+    if a == 0.0 {
+        return Color::new_rgba(0, 0, 0, 0);
+    }
+
+    let mix = |f: u8, b: u8| encode((decode(f) * fg_a + decode(b) * bg_a * (1.0 - fg_a)) / a);
+    Color::new_rgba(
+        mix(fg.red, bg.red),
+        mix(fg.green, bg.green),
+        mix(fg.blue, bg.blue),
+        to_u8(a),
+    )
+}
+
+#[inline]
+fn to_u8(v: f64) -> u8 {
+    (f64_bound(0.0, v, 1.0) * 255.0).round() as u8
+}
+
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+
+    to_u8(c)
+}
+
 #[inline]
 fn bound<T: Ord>(min: T, val: T, max: T) -> T {
     std::cmp::max(min, std::cmp::min(max, val))
@@ -443,6 +561,24 @@ mod tests {
         Color::new_rgb(255, 255, 255)
     );
 
+    test!(
+        rgb_numeric_overflow,
+        "rgb(300, -20, 128)",
+        Color::new_rgb(255, 0, 128)
+    );
+
+    test!(
+        rgb_numeric_float_overflow,
+        "rgb(255.6, -0.4, -300.5)",
+        Color::new_rgb(255, 0, 0)
+    );
+
+    test!(
+        rgb_percentage_negative_float,
+        "rgb(-33.3%, 100.1%, 50.5%)",
+        Color::new_rgb(0, 255, 129)
+    );
+
     test!(
         name_red,
         "red",
@@ -551,6 +687,67 @@ mod tests {
         Color::new_rgba(127, 255, 127, 127)
     );
 
+    test!(
+        rgb_trailing_comma,
+        "rgb(255, 0, 0,)",
+        Color::new_rgba(255, 0, 0, 255)
+    );
+
+    test!(
+        rgba_trailing_comma,
+        "rgba(255,0,0,0.5,)",
+        Color::new_rgba(255, 0, 0, 127)
+    );
+
+    test!(
+        hsl_trailing_comma,
+        "hsl(120, 100%, 75%, )",
+        Color::new_rgba(127, 255, 127, 255)
+    );
+
+    #[test]
+    fn lerp_1() {
+        let c = Color::black().lerp(Color::white(), 0.5);
+        assert_eq!(c, Color::new_rgb(128, 128, 128));
+
+        let c = Color::black().lerp_linear(Color::white(), 0.5);
+        assert_eq!(c, Color::new_rgb(188, 188, 188));
+    }
+
+    #[test]
+    fn lerp_2() {
+        let c1 = Color::new_rgba(10, 20, 30, 0);
+        let c2 = Color::new_rgba(200, 100, 50, 255);
+        assert_eq!(c1.lerp(c2, 0.0), c1);
+        assert_eq!(c1.lerp(c2, 1.0), c2);
+        assert_eq!(c1.lerp(c2, 2.0), c2);
+        assert_eq!(c1.lerp_linear(c2, 0.0), c1);
+        assert_eq!(c1.lerp_linear(c2, 1.0), c2);
+    }
+
+    #[test]
+    fn blend_over_1() {
+        let fg = Color::new_rgba(255, 255, 255, 128);
+        assert_eq!(fg.blend_over(Color::black()), Color::new_rgb(128, 128, 128));
+        assert_eq!(fg.blend_over_linear(Color::black()), Color::new_rgb(188, 188, 188));
+    }
+
+    #[test]
+    fn blend_over_2() {
+        assert_eq!(Color::red().blend_over(Color::blue()), Color::red());
+        assert_eq!(Color::new_rgba(255, 0, 0, 0).blend_over(Color::blue()), Color::blue());
+        assert_eq!(Color::new_rgba(255, 0, 0, 0).blend_over(Color::new_rgba(0, 0, 255, 0)),
+                   Color::new_rgba(0, 0, 0, 0));
+    }
+
+    #[test]
+    fn all_named_1() {
+        // 147 CSS3 extended colors and `transparent`.
+        assert_eq!(Color::all_named().count(), 148);
+        assert!(Color::all_named().any(|(name, c)| name == "red" && c == Color::new_rgb(255, 0, 0)));
+        assert!(Color::all_named().all(|(name, c)| Color::from_str(name).unwrap() == c));
+    }
+
     macro_rules! test_err {
         ($name:ident, $text:expr, $err:expr) => {
             #[test]
@@ -563,7 +760,13 @@ mod tests {
     test_err!(
         not_a_color_1,
         "text",
-        "invalid value"
+        "unexpected data at position 1"
+    );
+
+    test_err!(
+        not_a_color_2,
+        "  reddish",
+        "unexpected data at position 3"
     );
 
     test_err!(
@@ -587,9 +790,23 @@ mod tests {
     test_err!(
         invalid_input_2,
         "#9ߞpx! ;",
-        "invalid value"
+        "unexpected data at position 1"
     );
 
+    test_err!(
+        invalid_hex_length_1,
+        " #12345",
+        "unexpected data at position 2"
+    );
+
+    #[test]
+    fn invalid_name_in_stream_1() {
+        let mut s = crate::Stream::from("red, bluish");
+        assert_eq!(s.parse_color().unwrap(), Color::red());
+        s.advance(1);
+        assert_eq!(s.parse_color().unwrap_err(), crate::Error::UnexpectedData(6));
+    }
+
     test_err!(
         rgba_with_percent_alpha,
         "rgba(10, 20, 30, 5%)",
@@ -601,4 +818,18 @@ mod tests {
         "rgb(140%, -10mm, 130pt)",
         "invalid number at position 14"
     );
+
+    test_err!(
+        rgb_double_trailing_comma,
+        "rgb(255, 0, 0,,)",
+        "invalid number at position 15"
+    );
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let c = Color::new_rgba(10, 20, 30, 40);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(serde_json::from_str::<Color>(&json).unwrap(), c);
+    }
 }
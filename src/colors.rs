@@ -192,6 +192,10 @@ pub fn from_str(text: &str) -> Option<Color> {
     COLORS.get(text).cloned()
 }
 
+pub fn all() -> impl Iterator<Item = (&'static str, Color)> {
+    COLORS.entries.iter().cloned()
+}
+
 // A stripped down `phf` crate fork.
 //
 // https://github.com/sfackler/rust-phf
@@ -19,23 +19,19 @@ impl DirectionalPosition {
     /// Checks whether the value can be a horizontal position.
     #[inline]
     pub fn is_horizontal(&self) -> bool {
-        match self {
-            DirectionalPosition::Center
-            | DirectionalPosition::Left
-            | DirectionalPosition::Right => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            DirectionalPosition::Center | DirectionalPosition::Left | DirectionalPosition::Right
+        )
     }
 
     /// Checks whether the value can be a vertical position.
     #[inline]
     pub fn is_vertical(&self) -> bool {
-        match self {
-            DirectionalPosition::Center
-            | DirectionalPosition::Top
-            | DirectionalPosition::Bottom => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            DirectionalPosition::Center | DirectionalPosition::Top | DirectionalPosition::Bottom
+        )
     }
 }
 
@@ -53,6 +49,20 @@ impl From<DirectionalPosition> for Length {
     }
 }
 
+impl std::fmt::Display for DirectionalPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let name = match self {
+            DirectionalPosition::Top => "top",
+            DirectionalPosition::Center => "center",
+            DirectionalPosition::Bottom => "bottom",
+            DirectionalPosition::Right => "right",
+            DirectionalPosition::Left => "left",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
 impl std::str::FromStr for DirectionalPosition {
     type Err = Error;
 
@@ -71,26 +81,39 @@ impl std::str::FromStr for DirectionalPosition {
 
 impl<'a> Stream<'a> {
     /// Parses a directional position [`left`, `center`, `right`, `bottom`, `top`] from the stream.
+    ///
+    /// Only the keyword is consumed, so a following length offset
+    /// can be parsed separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svgtypes::{DirectionalPosition, Length, LengthUnit, Stream};
+    ///
+    /// let mut s = Stream::from("left 10px");
+    /// assert_eq!(s.parse_directional_position().unwrap(), DirectionalPosition::Left);
+    /// assert_eq!(s.parse_length().unwrap(), Length::new(10.0, LengthUnit::Px));
+    /// ```
     pub fn parse_directional_position(&mut self) -> Result<DirectionalPosition, Error> {
         self.skip_spaces();
 
         if self.starts_with(b"left") {
             self.advance(4);
-            return Ok(DirectionalPosition::Left);
+            Ok(DirectionalPosition::Left)
         } else if self.starts_with(b"right") {
             self.advance(5);
-            return Ok(DirectionalPosition::Right);
+            Ok(DirectionalPosition::Right)
         } else if self.starts_with(b"top") {
             self.advance(3);
-            return Ok(DirectionalPosition::Top);
+            Ok(DirectionalPosition::Top)
         } else if self.starts_with(b"bottom") {
             self.advance(6);
-            return Ok(DirectionalPosition::Bottom);
+            Ok(DirectionalPosition::Bottom)
         } else if self.starts_with(b"center") {
             self.advance(6);
-            return Ok(DirectionalPosition::Center);
+            Ok(DirectionalPosition::Center)
         } else {
-            return Err(Error::InvalidString(
+            Err(Error::InvalidString(
                 vec![
                     self.slice_tail().to_string(),
                     "left".to_string(),
@@ -100,7 +123,7 @@ impl<'a> Stream<'a> {
                     "center".to_string(),
                 ],
                 self.calc_char_pos(),
-            ));
+            ))
         }
     }
 }
@@ -144,6 +167,13 @@ mod tests {
         assert_eq!(s.parse_directional_position().unwrap(), DirectionalPosition::Left);
     }
 
+    #[test]
+    fn display_1() {
+        for text in &["left", "right", "center", "top", "bottom"] {
+            assert_eq!(DirectionalPosition::from_str(text).unwrap().to_string(), *text);
+        }
+    }
+
     #[test]
     fn err_1() {
         let mut s = Stream::from("something");
@@ -22,16 +22,14 @@ impl std::str::FromStr for EnableBackground {
     fn from_str(text: &str) -> Result<Self, Self::Err> {
         let mut s = Stream::from(text);
         s.skip_spaces();
-        if s.starts_with(b"accumulate") {
-            s.advance(10);
+        if s.try_consume_keyword("accumulate") {
             s.skip_spaces();
             if !s.at_end() {
                 return Err(Error::UnexpectedData(s.calc_char_pos()));
             }
 
             Ok(EnableBackground::Accumulate)
-        } else if s.starts_with(b"new") {
-            s.advance(3);
+        } else if s.try_consume_keyword("new") {
             s.skip_spaces();
             if s.at_end() {
                 return Ok(EnableBackground::New);
@@ -125,4 +123,16 @@ mod tests {
         assert_eq!(EnableBackground::from_str("new 0 0 0 0").unwrap_err().to_string(),
                    "invalid value");
     }
+
+    #[test]
+    fn err_6() {
+        assert_eq!(EnableBackground::from_str("newer").unwrap_err().to_string(),
+                   "invalid value");
+    }
+
+    #[test]
+    fn err_7() {
+        assert_eq!(EnableBackground::from_str("accumulated").unwrap_err().to_string(),
+                   "invalid value");
+    }
 }
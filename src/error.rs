@@ -1,5 +1,5 @@
 /// List of all errors.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Error {
     /// An input data ended earlier than expected.
     ///
@@ -41,6 +41,11 @@ pub enum Error {
 
     /// An invalid number.
     InvalidNumber(usize),
+
+    /// An unknown unit suffix after a number.
+    ///
+    /// Contains the suffix and its position.
+    InvalidUnit(String, usize),
 }
 
 impl std::fmt::Display for Error {
@@ -86,6 +91,9 @@ impl std::fmt::Display for Error {
             Error::InvalidNumber(pos) => {
                 write!(f, "invalid number at position {}", pos)
             }
+            Error::InvalidUnit(ref unit, pos) => {
+                write!(f, "invalid unit '{}' at position {}", unit, pos)
+            }
         }
     }
 }
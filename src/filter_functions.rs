@@ -135,8 +135,7 @@ impl<'a> Iterator for FilterValueListParser<'a> {
             return None;
         }
 
-        if self.stream.starts_with(b"none") {
-            self.stream.advance(4);
+        if self.stream.try_consume_keyword("none") {
             self.stream.skip_spaces();
 
             if self.stream.at_end() {
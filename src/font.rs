@@ -1,6 +1,7 @@
 use crate::stream::{ByteExt, Stream};
-use crate::Error;
+use crate::{Error, Length, LengthUnit};
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// Parses a list of font families and generic families from a string.
 pub fn parse_font_families(text: &str) -> Result<Vec<FontFamily>, Error> {
@@ -15,6 +16,158 @@ pub fn parse_font_families(text: &str) -> Result<Vec<FontFamily>, Error> {
     Ok(font_families)
 }
 
+/// Writes a list of font families into a string.
+///
+/// Families are separated by `, `. Generic families are written as keywords,
+/// while named families are quoted only when they wouldn't be parsed back as-is,
+/// e.g. when they contain spaces or match a reserved keyword.
+/// Quotes and backslashes inside a quoted name are escaped with a backslash.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::{parse_font_families, write_font_families};
+///
+/// let families = parse_font_families("'Times New Roman', Arial, sans-serif").unwrap();
+/// assert_eq!(write_font_families(&families), "\"Times New Roman\", Arial, sans-serif");
+/// ```
+pub fn write_font_families(families: &[FontFamily]) -> String {
+    let mut out = String::new();
+    for (i, family) in families.iter().enumerate() {
+        if i != 0 {
+            out.push_str(", ");
+        }
+
+        match family {
+            FontFamily::Named(name) if needs_quotes(name) => {
+                let quote = if name.contains('"') { '\'' } else { '"' };
+                out.push(quote);
+                for c in name.chars() {
+                    if c == quote || c == '\\' {
+                        out.push('\\');
+                    }
+
+                    out.push(c);
+                }
+                out.push(quote);
+            }
+            FontFamily::Named(name) => out.push_str(name),
+            _ => out.push_str(&family.to_string()),
+        }
+    }
+
+    out
+}
+
+fn needs_quotes(name: &str) -> bool {
+    if name.contains(' ') {
+        return true;
+    }
+
+    if matches!(
+        name.to_ascii_lowercase().as_str(),
+        "serif"
+            | "sans-serif"
+            | "cursive"
+            | "fantasy"
+            | "monospace"
+            | "system-ui"
+            | "ui-serif"
+            | "ui-sans-serif"
+            | "ui-monospace"
+            | "ui-rounded"
+            | "math"
+            | "emoji"
+            | "inherit"
+            | "initial"
+            | "unset"
+            | "default"
+    ) {
+        return true;
+    }
+
+    let mut s = Stream::from(name);
+    !(s.parse_ident().is_ok() && s.at_end())
+}
+
+/// Resolves CSS escapes of non-hex characters, like `\'`.
+///
+/// Hex escapes are kept as is.
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut iter = text.chars().peekable();
+    while let Some(c) = iter.next() {
+        if c == '\\' {
+            if let Some(&next) = iter.peek() {
+                if !next.is_ascii_hexdigit() {
+                    out.push(next);
+                    iter.next();
+                    continue;
+                }
+            }
+        }
+
+        out.push(c);
+    }
+
+    out
+}
+
+/// Parses a [`font-weight`](https://www.w3.org/TR/css-fonts-4/#font-weight-prop)
+/// into a numeric weight.
+///
+/// `normal` is 400 and `bold` is 700. `bolder` and `lighter` are resolved
+/// against the `inherited` weight using the CSS step table.
+/// Numbers must be in the `1..=1000` range and are rounded.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::parse_font_weight;
+///
+/// assert_eq!(parse_font_weight("bold", 400).unwrap(), 700);
+/// assert_eq!(parse_font_weight("bolder", 700).unwrap(), 900);
+/// assert_eq!(parse_font_weight("600", 400).unwrap(), 600);
+/// ```
+pub fn parse_font_weight(text: &str, inherited: u16) -> Result<u16, Error> {
+    let mut s = Stream::from(text);
+    s.skip_spaces();
+
+    let weight = if s.try_consume_keyword("normal") {
+        400
+    } else if s.try_consume_keyword("bold") {
+        700
+    } else if s.try_consume_keyword("bolder") {
+        match inherited {
+            0..=349 => 400,
+            350..=549 => 700,
+            550..=899 => 900,
+            _ => inherited,
+        }
+    } else if s.try_consume_keyword("lighter") {
+        match inherited {
+            0..=99 => inherited,
+            100..=549 => 100,
+            550..=749 => 400,
+            _ => 700,
+        }
+    } else {
+        let n = s.parse_number()?;
+        if !(1.0..=1000.0).contains(&n) {
+            return Err(Error::InvalidValue);
+        }
+
+        n.round() as u16
+    };
+
+    s.skip_spaces();
+    if !s.at_end() {
+        return Err(Error::UnexpectedData(s.calc_char_pos()));
+    }
+
+    Ok(weight)
+}
+
 /// A type of font family.
 #[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum FontFamily {
@@ -28,6 +181,20 @@ pub enum FontFamily {
     Fantasy,
     /// A monospace font.
     Monospace,
+    /// The default user interface font of the platform.
+    SystemUi,
+    /// A serif variant of the platform user interface font.
+    UiSerif,
+    /// A sans-serif variant of the platform user interface font.
+    UiSansSerif,
+    /// A monospace variant of the platform user interface font.
+    UiMonospace,
+    /// A rounded variant of the platform user interface font.
+    UiRounded,
+    /// A font for mathematical expressions.
+    Math,
+    /// A font for emoji characters.
+    Emoji,
     /// A custom named font.
     Named(String),
 }
@@ -40,49 +207,56 @@ impl Display for FontFamily {
             FontFamily::SansSerif => "sans-serif".to_string(),
             FontFamily::Cursive => "cursive".to_string(),
             FontFamily::Fantasy => "fantasy".to_string(),
+            FontFamily::SystemUi => "system-ui".to_string(),
+            FontFamily::UiSerif => "ui-serif".to_string(),
+            FontFamily::UiSansSerif => "ui-sans-serif".to_string(),
+            FontFamily::UiMonospace => "ui-monospace".to_string(),
+            FontFamily::UiRounded => "ui-rounded".to_string(),
+            FontFamily::Math => "math".to_string(),
+            FontFamily::Emoji => "emoji".to_string(),
             FontFamily::Named(s) => format!("\"{}\"", s),
         };
         write!(f, "{}", str)
     }
 }
 
+impl FromStr for FontFamily {
+    type Err = Error;
+
+    /// Parses a single font family.
+    ///
+    /// Use [`parse_font_families`] to parse a comma-separated list.
+    ///
+    /// [`parse_font_families`]: fn.parse_font_families.html
+    fn from_str(text: &str) -> Result<Self, Error> {
+        let mut s = Stream::from(text);
+        s.skip_spaces();
+        let family = s.parse_font_family()?;
+
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(Error::UnexpectedData(s.calc_char_pos()));
+        }
+
+        if family == FontFamily::Named(String::new()) {
+            return Err(Error::InvalidValue);
+        }
+
+        Ok(family)
+    }
+}
+
 impl<'a> Stream<'a> {
+    /// Parses a comma-separated list of font families.
+    ///
+    /// Empty families are skipped.
     pub fn parse_font_families(&mut self) -> Result<Vec<FontFamily>, Error> {
         let mut families = vec![];
 
         while !self.at_end() {
             self.skip_spaces();
 
-            let family = {
-                let ch = self.curr_byte()?;
-                if ch == b'\'' || ch == b'\"' {
-                    let res = self.parse_quoted_string()?;
-                    FontFamily::Named(res.to_string())
-                } else {
-                    let mut idents = vec![];
-
-                    while let Some(c) = self.chars().next() {
-                        if c != ',' {
-                            idents.push(self.parse_ident()?.to_string());
-                            self.skip_spaces();
-                        } else {
-                            break;
-                        }
-                    }
-
-                    let joined = idents.join(" ");
-
-                    // TODO: No CSS keyword must be matched as a family name...
-                    match joined.as_str() {
-                        "serif" => FontFamily::Serif,
-                        "sans-serif" => FontFamily::SansSerif,
-                        "cursive" => FontFamily::Cursive,
-                        "fantasy" => FontFamily::Fantasy,
-                        "monospace" => FontFamily::Monospace,
-                        _ => FontFamily::Named(joined),
-                    }
-                }
-            };
+            let family = self.parse_font_family()?;
 
             families.push(family);
 
@@ -105,6 +279,50 @@ impl<'a> Stream<'a> {
 
         Ok(families)
     }
+
+    fn parse_font_family(&mut self) -> Result<FontFamily, Error> {
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
+
+        if self.is_curr_byte_one_of(b"'\"") {
+            let res = self.parse_quoted_string()?;
+            Ok(FontFamily::Named(unescape(res)))
+        } else {
+            let mut idents = vec![];
+
+            while let Some(c) = self.chars().next() {
+                if c != ',' {
+                    idents.push(self.parse_ident()?.to_string());
+                    self.skip_spaces();
+                } else {
+                    break;
+                }
+            }
+
+            let joined = idents.join(" ");
+
+            let family = match joined.as_str() {
+                "serif" => FontFamily::Serif,
+                "sans-serif" => FontFamily::SansSerif,
+                "cursive" => FontFamily::Cursive,
+                "fantasy" => FontFamily::Fantasy,
+                "monospace" => FontFamily::Monospace,
+                "system-ui" => FontFamily::SystemUi,
+                "ui-serif" => FontFamily::UiSerif,
+                "ui-sans-serif" => FontFamily::UiSansSerif,
+                "ui-monospace" => FontFamily::UiMonospace,
+                "ui-rounded" => FontFamily::UiRounded,
+                "math" => FontFamily::Math,
+                "emoji" => FontFamily::Emoji,
+                // CSS-wide keywords cannot be used as unquoted family names.
+                "inherit" | "initial" | "unset" | "default" => return Err(Error::InvalidValue),
+                _ => FontFamily::Named(joined),
+            };
+
+            Ok(family)
+        }
+    }
 }
 
 /// The values of a [`font` shorthand](https://www.w3.org/TR/css-fonts-3/#font-prop).
@@ -120,6 +338,8 @@ pub struct FontShorthand<'a> {
     pub font_stretch: Option<&'a str>,
     /// The font size.
     pub font_size: &'a str,
+    /// The line height, if set using the `/` form.
+    pub line_height: Option<&'a str>,
     /// The font family.
     pub font_family: &'a str,
 }
@@ -131,6 +351,7 @@ impl<'a> FontShorthand<'a> {
     /// an owned value as a return type.
     ///
     /// [font]: https://www.w3.org/TR/css-fonts-3/#font-prop
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(text: &'a str) -> Result<Self, Error> {
         let mut stream = Stream::from(text);
         stream.skip_spaces();
@@ -198,11 +419,13 @@ impl<'a> FontShorthand<'a> {
         let font_size = stream.slice_back(prev_pos);
         stream.skip_spaces();
 
+        let mut line_height = None;
         if stream.curr_byte()? == b'/' {
-            // We should ignore line height since it has no effect in SVG.
             stream.advance(1);
             stream.skip_spaces();
+            let start = stream.pos();
             let _ = stream.parse_length()?;
+            line_height = Some(stream.slice_back(start));
             stream.skip_spaces();
         }
 
@@ -218,11 +441,95 @@ impl<'a> FontShorthand<'a> {
             font_weight,
             font_stretch,
             font_size,
+            line_height,
             font_family,
         })
     }
 }
 
+/// The values of a [`font` shorthand](https://www.w3.org/TR/css-fonts-3/#font-prop)
+/// with the size, weight, line height and families already parsed.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::{FontFamily, FontShorthandParsed, Length, LengthUnit};
+///
+/// let font = FontShorthandParsed::from_str("bold 12pt/14pt Arial, serif").unwrap();
+/// assert_eq!(font.font_weight, 700);
+/// assert_eq!(font.font_size, Length::new(12.0, LengthUnit::Pt));
+/// assert_eq!(font.line_height, Some(Length::new(14.0, LengthUnit::Pt)));
+/// assert_eq!(font.font_family, vec![FontFamily::Named("Arial".to_string()), FontFamily::Serif]);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct FontShorthandParsed<'a> {
+    /// The font style.
+    pub font_style: Option<&'a str>,
+    /// The font variant.
+    pub font_variant: Option<&'a str>,
+    /// The numeric font weight.
+    ///
+    /// `normal` is 400 and `bold` is 700. Relative weights are resolved against 400.
+    pub font_weight: u16,
+    /// The font stretch.
+    pub font_stretch: Option<&'a str>,
+    /// The font size.
+    ///
+    /// Absolute size keywords are resolved into pixels and relative ones into `em`.
+    pub font_size: Length,
+    /// The line height.
+    pub line_height: Option<Length>,
+    /// The font families.
+    pub font_family: Vec<FontFamily>,
+}
+
+impl<'a> FontShorthandParsed<'a> {
+    /// Parses the `font` shorthand from a string.
+    ///
+    /// We can't use the `FromStr` trait because it requires
+    /// an owned value as a return type.
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(text: &'a str) -> Result<Self, Error> {
+        let font = FontShorthand::from_str(text)?;
+
+        let line_height = match font.line_height {
+            Some(v) => Some(Length::from_str(v)?),
+            None => None,
+        };
+
+        Ok(Self {
+            font_style: font.font_style,
+            font_variant: font.font_variant,
+            font_weight: match font.font_weight {
+                Some(weight) => parse_font_weight(weight, 400)?,
+                None => 400,
+            },
+            font_stretch: font.font_stretch,
+            font_size: font_size_value(font.font_size)?,
+            line_height,
+            font_family: parse_font_families(font.font_family)?,
+        })
+    }
+}
+
+fn font_size_value(size: &str) -> Result<Length, Error> {
+    // https://www.w3.org/TR/css-fonts-4/#absolute-size-mapping
+    let px = match size {
+        "xx-small" => 9.0,
+        "x-small" => 10.0,
+        "small" => 13.0,
+        "medium" => 16.0,
+        "large" => 18.0,
+        "x-large" => 24.0,
+        "xx-large" => 32.0,
+        "larger" => return Ok(Length::new(1.2, LengthUnit::Em)),
+        "smaller" => return Ok(Length::new(1.0 / 1.2, LengthUnit::Em)),
+        _ => return Length::from_str(size),
+    };
+
+    Ok(Length::new(px, LengthUnit::Px))
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -269,6 +576,16 @@ mod tests {
         vec![named!("简体中文"), SANS_SERIF, named!("日本語フォント"), named!("Arial")]);
 
     font_family!(font_family_16, "", vec![]);
+    font_family!(font_family_17, "system-ui", vec![FontFamily::SystemUi]);
+    font_family!(font_family_18, "ui-serif", vec![FontFamily::UiSerif]);
+    font_family!(font_family_19, "ui-sans-serif", vec![FontFamily::UiSansSerif]);
+    font_family!(font_family_20, "ui-monospace", vec![FontFamily::UiMonospace]);
+    font_family!(font_family_21, "ui-rounded", vec![FontFamily::UiRounded]);
+    font_family!(font_family_22, "math", vec![FontFamily::Math]);
+    font_family!(font_family_23, "emoji", vec![FontFamily::Emoji]);
+    font_family!(font_family_24, "'system-ui', emoji, 'math'",
+        vec![named!("system-ui"), FontFamily::Emoji, named!("math")]);
+    font_family!(font_family_25, "Default Sans, 'inherit'", vec![named!("Default Sans"), named!("inherit")]);
 
     macro_rules! font_family_err {
         ($name:ident, $text:expr, $result:expr) => (
@@ -284,14 +601,85 @@ mod tests {
     font_family_err!(font_family_err_4, "test@foo, sans-serif", "invalid ident");
     font_family_err!(font_family_err_5, "#POUND, sans-serif", "invalid ident");
     font_family_err!(font_family_err_6, "Hawaii 5-0, sans-serif", "invalid ident");
+    font_family_err!(font_family_err_7, "inherit", "invalid value");
+    font_family_err!(font_family_err_8, "Arial, initial", "invalid value");
+    font_family_err!(font_family_err_9, "unset, serif", "invalid value");
+    font_family_err!(font_family_err_10, "default", "invalid value");
+
+    macro_rules! single_font_family {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                use std::str::FromStr;
+                assert_eq!(FontFamily::from_str($text).unwrap(), $result);
+            }
+        )
+    }
+
+    single_font_family!(single_font_family_1, "serif", SERIF);
+    single_font_family!(single_font_family_2, "\"My Font\"", named!("My Font"));
+    single_font_family!(single_font_family_3, "  Times   New Roman ", named!("Times New Roman"));
+    single_font_family!(single_font_family_4, "'serif'", named!("serif"));
+    single_font_family!(single_font_family_5, "'It\\'s'", named!("It's"));
+
+    macro_rules! single_font_family_err {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                use std::str::FromStr;
+                assert_eq!(FontFamily::from_str($text).unwrap_err().to_string(), $result);
+            }
+        )
+    }
+
+    single_font_family_err!(single_font_family_err_1, "a, b", "unexpected data at position 2");
+    single_font_family_err!(single_font_family_err_2, "", "unexpected end of stream");
+    single_font_family_err!(single_font_family_err_3, "''", "invalid value");
+
+    macro_rules! write_font_families {
+        ($name:ident, $families:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(write_font_families(&$families), $result);
+            }
+        )
+    }
+
+    write_font_families!(write_font_families_1, [named!("Times New Roman"), SANS_SERIF], "\"Times New Roman\", sans-serif");
+    write_font_families!(write_font_families_2, [named!("Arial"), MONOSPACE, CURSIVE], "Arial, monospace, cursive");
+    write_font_families!(write_font_families_3, [named!("serif"), SERIF], "\"serif\", serif");
+    write_font_families!(write_font_families_4, [named!("Red/Black"), named!("Hawaii 5-0")], "\"Red/Black\", \"Hawaii 5-0\"");
+    write_font_families!(write_font_families_5, [named!("My \"Font\"")], "'My \"Font\"'");
+    write_font_families!(write_font_families_6, [named!("Inherit")], "\"Inherit\"");
+    write_font_families!(write_font_families_7, [], "");
+    write_font_families!(write_font_families_8, [FontFamily::SystemUi, FontFamily::UiRounded, named!("emoji")],
+        "system-ui, ui-rounded, \"emoji\"");
+
+    #[test]
+    fn write_font_families_round_trip() {
+        let families = parse_font_families("'Times New Roman', sans-serif").unwrap();
+        let text = write_font_families(&families);
+        assert_eq!(text, "\"Times New Roman\", sans-serif");
+        assert_eq!(parse_font_families(&text).unwrap(), families);
+
+        let families = vec![named!("a'b\"c"), named!("a\\b\\")];
+        let text = write_font_families(&families);
+        assert_eq!(text, "'a\\'b\"c', \"a\\\\b\\\\\"");
+        assert_eq!(parse_font_families(&text).unwrap(), families);
+    }
 
     impl<'a> FontShorthand<'a> {
         fn new(font_style: Option<&'a str>, font_variant: Option<&'a str>, font_weight: Option<&'a str>,
                    font_stretch: Option<&'a str>, font_size: &'a str, font_family: &'a str) -> Self {
             Self {
-                font_style, font_variant, font_weight, font_stretch, font_size, font_family
+                font_style, font_variant, font_weight, font_stretch, font_size, line_height: None, font_family
             }
         }
+
+        fn with_line_height(mut self, line_height: &'a str) -> Self {
+            self.line_height = Some(line_height);
+            self
+        }
     }
 
     macro_rules! font_shorthand {
@@ -304,15 +692,16 @@ mod tests {
     }
 
     font_shorthand!(font_shorthand_1, "12pt/14pt sans-serif",
-        FontShorthand::new(None, None, None, None, "12pt", "sans-serif"));
+        FontShorthand::new(None, None, None, None, "12pt", "sans-serif").with_line_height("14pt"));
     font_shorthand!(font_shorthand_2, "80% sans-serif",
         FontShorthand::new(None, None, None, None, "80%", "sans-serif"));
     font_shorthand!(font_shorthand_3, "bold italic large Palatino, serif",
         FontShorthand::new(Some("italic"), None, Some("bold"), None, "large", "Palatino, serif"));
     font_shorthand!(font_shorthand_4, "x-large/110% \"new century schoolbook\", serif",
-        FontShorthand::new(None, None, None, None, "x-large", "\"new century schoolbook\", serif"));
+        FontShorthand::new(None, None, None, None, "x-large", "\"new century schoolbook\", serif")
+            .with_line_height("110%"));
     font_shorthand!(font_shorthand_5, "normal small-caps 120%/120% fantasy",
-        FontShorthand::new(None, Some("small-caps"), None, None, "120%", "fantasy"));
+        FontShorthand::new(None, Some("small-caps"), None, None, "120%", "fantasy").with_line_height("120%"));
     font_shorthand!(font_shorthand_6, "condensed oblique 12pt \"Helvetica Neue\", serif",
         FontShorthand::new(Some("oblique"), None, None, Some("condensed"), "12pt", "\"Helvetica Neue\", serif"));
     font_shorthand!(font_shorthand_7, "italic 500 2em sans-serif, 'Noto Sans'",
@@ -321,6 +710,8 @@ mod tests {
         FontShorthand::new(None, None, None, None, "xx-large", "'Noto Sans'"));
     font_shorthand!(font_shorthand_9, "small-caps normal normal italic xx-small Times",
         FontShorthand::new(Some("italic"), Some("small-caps"), None, None, "xx-small", "Times"));
+    font_shorthand!(font_shorthand_10, "16px / 1.5 serif",
+        FontShorthand::new(None, None, None, None, "16px", "serif").with_line_height("1.5"));
 
 
     macro_rules! font_shorthand_err {
@@ -339,4 +730,77 @@ mod tests {
     font_shorthand_err!(font_shorthand_err_5, "'Noto Sans' 13pt", Error::UnexpectedData(0));
     font_shorthand_err!(font_shorthand_err_6,
         "small-caps normal normal normal italic xx-large Times", Error::UnexpectedData(32));
+
+    macro_rules! font_weight {
+        ($name:ident, $text:expr, $inherited:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(parse_font_weight($text, $inherited).unwrap(), $result);
+            }
+        )
+    }
+
+    font_weight!(font_weight_1, "normal", 700, 400);
+    font_weight!(font_weight_2, "bold", 400, 700);
+    font_weight!(font_weight_3, "600", 400, 600);
+    font_weight!(font_weight_4, " 450.6 ", 400, 451);
+    font_weight!(font_weight_5, "lighter", 400, 100);
+    font_weight!(font_weight_6, "lighter", 50, 50);
+    font_weight!(font_weight_7, "lighter", 600, 400);
+    font_weight!(font_weight_8, "lighter", 800, 700);
+    font_weight!(font_weight_9, "bolder", 300, 400);
+    font_weight!(font_weight_10, "bolder", 400, 700);
+    font_weight!(font_weight_11, "bolder", 700, 900);
+    font_weight!(font_weight_12, "bolder", 950, 950);
+
+    macro_rules! font_weight_err {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(parse_font_weight($text, 400).unwrap_err().to_string(), $result);
+            }
+        )
+    }
+
+    font_weight_err!(font_weight_err_1, "0", "invalid value");
+    font_weight_err!(font_weight_err_2, "1001", "invalid value");
+    font_weight_err!(font_weight_err_3, "boldest", "invalid number at position 1");
+    font_weight_err!(font_weight_err_4, "bold 700", "unexpected data at position 6");
+    font_weight_err!(font_weight_err_5, "", "invalid number at position 1");
+
+    macro_rules! font_shorthand_parsed {
+        ($name:ident, $text:expr, $weight:expr, $size:expr, $line_height:expr, $family:expr) => (
+            #[test]
+            fn $name() {
+                let font = FontShorthandParsed::from_str($text).unwrap();
+                assert_eq!(font.font_weight, $weight);
+                assert_eq!(font.font_size, $size);
+                assert_eq!(font.line_height, $line_height);
+                assert_eq!(font.font_family, $family);
+            }
+        )
+    }
+
+    font_shorthand_parsed!(font_shorthand_parsed_1, "12pt/14pt sans-serif",
+        400, Length::new(12.0, LengthUnit::Pt), Some(Length::new(14.0, LengthUnit::Pt)), vec![SANS_SERIF]);
+    font_shorthand_parsed!(font_shorthand_parsed_2, "80% sans-serif",
+        400, Length::new(80.0, LengthUnit::Percent), None, vec![SANS_SERIF]);
+    font_shorthand_parsed!(font_shorthand_parsed_3, "bold italic large Palatino, serif",
+        700, Length::new(18.0, LengthUnit::Px), None, vec![named!("Palatino"), SERIF]);
+    font_shorthand_parsed!(font_shorthand_parsed_4, "x-large/110% \"new century schoolbook\", serif",
+        400, Length::new(24.0, LengthUnit::Px), Some(Length::new(110.0, LengthUnit::Percent)),
+        vec![named!("new century schoolbook"), SERIF]);
+    font_shorthand_parsed!(font_shorthand_parsed_5, "normal small-caps 120%/120% fantasy",
+        400, Length::new(120.0, LengthUnit::Percent), Some(Length::new(120.0, LengthUnit::Percent)), vec![FANTASY]);
+    font_shorthand_parsed!(font_shorthand_parsed_6, "italic 500 2em sans-serif, 'Noto Sans'",
+        500, Length::new(2.0, LengthUnit::Em), None, vec![SANS_SERIF, named!("Noto Sans")]);
+    font_shorthand_parsed!(font_shorthand_parsed_7, "lighter smaller monospace",
+        100, Length::new(1.0 / 1.2, LengthUnit::Em), None, vec![MONOSPACE]);
+    font_shorthand_parsed!(font_shorthand_parsed_8, "bolder 12pt serif",
+        700, Length::new(12.0, LengthUnit::Pt), None, vec![SERIF]);
+
+    #[test]
+    fn font_shorthand_parsed_err_1() {
+        assert_eq!(FontShorthandParsed::from_str("12pt Red/Black").unwrap_err(), Error::InvalidIdent);
+    }
 }
@@ -59,6 +59,9 @@ impl<'a> FuncIRI<'a> {
 }
 
 impl<'a> Stream<'a> {
+    /// Parses an [IRI](https://www.w3.org/TR/SVG11/types.html#DataTypeIRI) link.
+    ///
+    /// Returns a link without the leading `#`.
     pub fn parse_iri(&mut self) -> Result<&'a str, Error> {
         self.skip_spaces();
         self.consume_byte(b'#')?;
@@ -69,14 +72,18 @@ impl<'a> Stream<'a> {
         Ok(link)
     }
 
+    /// Parses a [FuncIRI](https://www.w3.org/TR/SVG11/types.html#DataTypeFuncIRI) link.
+    ///
+    /// Returns a link without the `url(#` and `)`.
     pub fn parse_func_iri(&mut self) -> Result<&'a str, Error> {
         self.skip_spaces();
         self.consume_string(b"url(")?;
         self.skip_spaces();
 
-        let quote = match self.curr_byte() {
-            Ok(b'\'') | Ok(b'"') => self.curr_byte().ok(),
-            _ => None,
+        let quote = if self.is_curr_byte_one_of(b"'\"") {
+            Some(self.curr_byte_unchecked())
+        } else {
+            None
         };
         if quote.is_some() {
             self.advance(1);
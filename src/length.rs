@@ -2,6 +2,7 @@ use crate::{Error, Stream};
 
 /// List of all SVG length units.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum LengthUnit {
     None,
@@ -20,6 +21,7 @@ pub enum LengthUnit {
 ///
 /// [`<length>`]: https://www.w3.org/TR/SVG2/types.html#InterfaceSVGLength
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub struct Length {
     pub number: f64,
@@ -52,6 +54,47 @@ impl Length {
             unit: LengthUnit::None,
         }
     }
+
+    /// Checks that the length number is zero, regardless of the unit.
+    ///
+    /// In CSS, a zero length can be written without a unit,
+    /// so `0`, `0px` and `0%` are all the same.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.number.abs() <= f64::EPSILON
+    }
+
+    /// Adds two lengths with the same unit.
+    ///
+    /// Returns `None` when units are different, since they cannot be
+    /// converted without a context.
+    #[inline]
+    pub fn checked_add(self, other: Length) -> Option<Length> {
+        if self.unit == other.unit {
+            Some(Length::new(self.number + other.number, self.unit))
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts two lengths with the same unit.
+    ///
+    /// Returns `None` when units are different, since they cannot be
+    /// converted without a context.
+    #[inline]
+    pub fn checked_sub(self, other: Length) -> Option<Length> {
+        if self.unit == other.unit {
+            Some(Length::new(self.number - other.number, self.unit))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a length with an absolute number and the same unit.
+    #[inline]
+    pub fn abs(self) -> Length {
+        Length::new(self.number.abs(), self.unit)
+    }
 }
 
 impl Default for Length {
@@ -61,6 +104,25 @@ impl Default for Length {
     }
 }
 
+impl std::fmt::Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let unit = match self.unit {
+            LengthUnit::None => "",
+            LengthUnit::Em => "em",
+            LengthUnit::Ex => "ex",
+            LengthUnit::Px => "px",
+            LengthUnit::In => "in",
+            LengthUnit::Cm => "cm",
+            LengthUnit::Mm => "mm",
+            LengthUnit::Pt => "pt",
+            LengthUnit::Pc => "pc",
+            LengthUnit::Percent => "%",
+        };
+
+        write!(f, "{}{}", self.number, unit)
+    }
+}
+
 impl std::str::FromStr for Length {
     type Err = Error;
 
@@ -77,6 +139,29 @@ impl std::str::FromStr for Length {
     }
 }
 
+/// Parses a single length that may be a bare number.
+///
+/// This is the canonical way to parse presentation attributes like
+/// [`stroke-width`] and [`stroke-dashoffset`].
+/// Behaves exactly like `Length::from_str`, so trailing data is an error.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::{parse_length_or_number, Length, LengthUnit};
+///
+/// assert_eq!(parse_length_or_number("2").unwrap(), Length::new_number(2.0));
+/// assert_eq!(parse_length_or_number("1.5mm").unwrap(), Length::new(1.5, LengthUnit::Mm));
+/// assert!(parse_length_or_number("2 3").is_err());
+/// ```
+///
+/// [`stroke-width`]: https://www.w3.org/TR/SVG2/painting.html#StrokeWidthProperty
+/// [`stroke-dashoffset`]: https://www.w3.org/TR/SVG2/painting.html#StrokeDashoffsetProperty
+#[inline]
+pub fn parse_length_or_number(text: &str) -> Result<Length, Error> {
+    text.parse()
+}
+
 impl<'a> Stream<'a> {
     /// Parses length from the stream.
     ///
@@ -85,6 +170,7 @@ impl<'a> Stream<'a> {
     /// # Notes
     ///
     /// - Suffix must be lowercase, otherwise it will be an error.
+    /// - An unknown suffix produces `InvalidUnit`.
     pub fn parse_length(&mut self) -> Result<Length, Error> {
         self.skip_spaces();
 
@@ -112,6 +198,13 @@ impl<'a> Stream<'a> {
             LengthUnit::Pt
         } else if self.starts_with(b"pc") {
             LengthUnit::Pc
+        } else if self.curr_byte_unchecked().is_ascii_alphabetic() {
+            let start = self.pos();
+            let suffix = self.consume_bytes(|_, c| c.is_ascii_alphabetic());
+            return Err(Error::InvalidUnit(
+                suffix.to_string(),
+                self.calc_char_pos_at(start),
+            ));
         } else {
             LengthUnit::None
         };
@@ -136,6 +229,15 @@ impl<'a> Stream<'a> {
         self.parse_list_separator();
         Ok(l)
     }
+
+    /// Parses two lengths from a list of lengths.
+    ///
+    /// Useful for `<length-percentage>` pairs, like gradient coordinates.
+    pub fn parse_length_pair(&mut self) -> Result<(Length, Length), Error> {
+        let x = self.parse_list_length()?;
+        let y = self.parse_list_length()?;
+        Ok((x, y))
+    }
 }
 
 /// A pull-based [`<list-of-length>`] parser.
@@ -178,6 +280,15 @@ impl<'a> Iterator for LengthListParser<'a> {
             Some(v)
         }
     }
+
+    /// Returns a conservative estimate based on the remaining stream length.
+    ///
+    /// Every item takes at least one byte. A parsing error is an item too,
+    /// so `1x` yields two items.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.remaining();
+        (remaining.min(1), Some(remaining))
+    }
 }
 
 #[rustfmt::skip]
@@ -208,6 +319,12 @@ mod tests {
     test_p!(parse_11, "1e0", Length::new(1.0, LengthUnit::None));
     test_p!(parse_12, "1.0e0", Length::new(1.0, LengthUnit::None));
     test_p!(parse_13, "1.0e0em", Length::new(1.0, LengthUnit::Em));
+    test_p!(parse_signed_1, "+10px", Length::new(10.0, LengthUnit::Px));
+    test_p!(parse_signed_2, "-10px", Length::new(-10.0, LengthUnit::Px));
+    test_p!(parse_signed_3, "+50%", Length::new(50.0, LengthUnit::Percent));
+    test_p!(parse_signed_4, "-50%", Length::new(-50.0, LengthUnit::Percent));
+    test_p!(parse_signed_5, "+.5em", Length::new(0.5, LengthUnit::Em));
+    test_p!(parse_signed_6, "+1", Length::new(1.0, LengthUnit::None));
 
     #[test]
     fn parse_14() {
@@ -230,9 +347,29 @@ mod tests {
     #[test]
     fn err_1() {
         let mut s = Stream::from("1q");
-        assert_eq!(s.parse_length().unwrap(), Length::new(1.0, LengthUnit::None));
         assert_eq!(s.parse_length().unwrap_err().to_string(),
-                   "invalid number at position 2");
+                   "invalid unit 'q' at position 2");
+    }
+
+    #[test]
+    fn err_unit_1() {
+        assert_eq!(Length::from_str("10").unwrap(), Length::new(10.0, LengthUnit::None));
+        assert_eq!(Length::from_str("10px").unwrap(), Length::new(10.0, LengthUnit::Px));
+        assert_eq!(Length::from_str("10q").unwrap_err(), Error::InvalidUnit("q".to_string(), 3));
+        assert_eq!(Length::from_str("10foo").unwrap_err(), Error::InvalidUnit("foo".to_string(), 3));
+        assert_eq!(Length::from_str(" 10PX").unwrap_err().to_string(), "invalid unit 'PX' at position 4");
+    }
+
+    #[test]
+    fn err_non_finite_1() {
+        assert_eq!(Length::from_str("1e999").unwrap_err().to_string(),
+                   "invalid number at position 1");
+        assert_eq!(Length::from_str("1e999px").unwrap_err().to_string(),
+                   "invalid number at position 1");
+        assert_eq!(Length::from_str("infpx").unwrap_err().to_string(),
+                   "invalid number at position 1");
+        assert_eq!(Length::from_str("nan%").unwrap_err().to_string(),
+                   "invalid number at position 1");
     }
 
     #[test]
@@ -240,4 +377,93 @@ mod tests {
         assert_eq!(Length::from_str("1mmx").unwrap_err().to_string(),
                    "unexpected data at position 4");
     }
+
+    #[test]
+    fn display_1() {
+        assert_eq!(Length::new(1.5, LengthUnit::None).to_string(), "1.5");
+        assert_eq!(Length::new(-10.0, LengthUnit::Px).to_string(), "-10px");
+        assert_eq!(Length::new(50.0, LengthUnit::Percent).to_string(), "50%");
+        assert_eq!(Length::new(2.0, LengthUnit::Em).to_string(), "2em");
+    }
+
+    #[test]
+    fn checked_add_1() {
+        let a = Length::new(10.0, LengthUnit::Px);
+        let b = Length::new(5.0, LengthUnit::Px);
+        assert_eq!(a.checked_add(b), Some(Length::new(15.0, LengthUnit::Px)));
+        assert_eq!(a.checked_sub(b), Some(Length::new(5.0, LengthUnit::Px)));
+    }
+
+    #[test]
+    fn checked_add_2() {
+        let a = Length::new(10.0, LengthUnit::Px);
+        let b = Length::new(5.0, LengthUnit::Percent);
+        assert_eq!(a.checked_add(b), None);
+        assert_eq!(a.checked_sub(b), None);
+    }
+
+    #[test]
+    fn is_zero_1() {
+        assert!(Length::from_str("0").unwrap().is_zero());
+        assert!(Length::from_str("0px").unwrap().is_zero());
+        assert!(Length::from_str("0%").unwrap().is_zero());
+        assert!(Length::from_str("-0mm").unwrap().is_zero());
+        assert!(!Length::from_str("0.1px").unwrap().is_zero());
+    }
+
+    #[test]
+    fn abs_1() {
+        assert_eq!(Length::new(-10.0, LengthUnit::Mm).abs(), Length::new(10.0, LengthUnit::Mm));
+    }
+
+    #[test]
+    fn parse_length_pair_1() {
+        let mut s = Stream::from("10% 20%");
+        assert_eq!(s.parse_length_pair().unwrap(),
+                   (Length::new(10.0, LengthUnit::Percent), Length::new(20.0, LengthUnit::Percent)));
+        assert!(s.at_end());
+    }
+
+    #[test]
+    fn parse_length_pair_2() {
+        let mut s = Stream::from("5px, 10px 15");
+        assert_eq!(s.parse_length_pair().unwrap(),
+                   (Length::new(5.0, LengthUnit::Px), Length::new(10.0, LengthUnit::Px)));
+        assert_eq!(s.slice_tail(), "15");
+    }
+
+    #[test]
+    fn length_or_number_1() {
+        for text in &["1", " 1.5em", "-2", "50%", "1e2px", "1 ", "1px2", "10foo", "", "x"] {
+            assert_eq!(parse_length_or_number(text), Length::from_str(text));
+        }
+    }
+
+    #[test]
+    fn size_hint_1() {
+        let p = LengthListParser::from("1,2%,3");
+        assert_eq!(p.size_hint(), (1, Some(6)));
+        assert_eq!(p.count(), 3);
+    }
+
+    #[test]
+    fn size_hint_2() {
+        let p = LengthListParser::from("1-");
+        assert_eq!(p.size_hint(), (1, Some(2)));
+        assert_eq!(p.count(), 2);
+    }
+
+    #[test]
+    fn parse_length_pair_err_1() {
+        let mut s = Stream::from("5px");
+        assert_eq!(s.parse_length_pair().unwrap_err().to_string(), "unexpected end of stream");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let l = Length::new(1.5, LengthUnit::Em);
+        let json = serde_json::to_string(&l).unwrap();
+        assert_eq!(serde_json::from_str::<Length>(&json).unwrap(), l);
+    }
 }
@@ -20,6 +20,7 @@
 - [`<IRI>`](https://www.w3.org/TR/SVG11/types.html#DataTypeIRI)
 - [`<FuncIRI>`](https://www.w3.org/TR/SVG11/types.html#DataTypeFuncIRI)
 - [`paint-order`](https://www.w3.org/TR/SVG2/painting.html#PaintOrder)
+- [`stroke-dasharray`](https://www.w3.org/TR/SVG2/painting.html#StrokeDasharrayProperty)
 
 ## Features
 
@@ -79,11 +80,12 @@ mod paint_order;
 mod path;
 mod points;
 mod stream;
+mod stroke;
 mod transform;
 mod transform_origin;
 mod viewbox;
 
-use crate::stream::{ByteExt, Stream};
+use crate::stream::ByteExt;
 
 pub use crate::angle::*;
 pub use crate::aspect_ratio::*;
@@ -100,6 +102,8 @@ pub use crate::paint::*;
 pub use crate::paint_order::*;
 pub use crate::path::*;
 pub use crate::points::*;
+pub use crate::stream::{parse_keyword, Stream};
+pub use crate::stroke::*;
 pub use crate::transform::*;
 pub use crate::transform_origin::*;
 pub use crate::viewbox::*;
@@ -36,6 +36,22 @@ impl<'a> Stream<'a> {
         // Strip off leading whitespaces.
         self.skip_spaces();
 
+        let start = self.pos();
+        let n = self.parse_number_allow_overflow()?;
+        if !n.is_finite() {
+            return Err(Error::InvalidNumber(self.calc_char_pos_at(start)));
+        }
+
+        Ok(n)
+    }
+
+    /// Parses number from the stream, like [`parse_number()`],
+    /// but returns an overflowing number, like `1e999`, as an infinity.
+    ///
+    /// [`parse_number()`]: #method.parse_number
+    pub(crate) fn parse_number_allow_overflow(&mut self) -> Result<f64, Error> {
+        self.skip_spaces();
+
         let start = self.pos();
 
         if self.at_end() {
@@ -46,6 +62,21 @@ impl<'a> Stream<'a> {
             .map_err(|_| Error::InvalidNumber(self.calc_char_pos_at(start)))
     }
 
+    /// Parses number from the stream and returns it along with its source text.
+    ///
+    /// Leading whitespaces are not included in the returned text.
+    ///
+    /// # Errors
+    ///
+    /// Returns only `InvalidNumber`.
+    pub fn parse_number_with_span(&mut self) -> Result<(f64, &'a str), Error> {
+        self.skip_spaces();
+
+        let start = self.pos();
+        let n = self.parse_number()?;
+        Ok((n, self.slice_back(start)))
+    }
+
     fn parse_number_impl(&mut self) -> Result<f64, Error> {
         let start = self.pos();
 
@@ -70,38 +101,36 @@ impl<'a> Stream<'a> {
             self.skip_digits();
         }
 
-        if let Ok(c) = self.curr_byte() {
-            if matches!(c, b'e' | b'E') {
-                let c2 = self.next_byte()?;
-                // Check for `em`/`ex`.
-                if c2 != b'm' && c2 != b'x' {
-                    self.advance(1);
+        // Consume exponent.
+        //
+        // An `e` without exponent digits is not a part of the number,
+        // like in `1em` or `1eL`, so it's left in the stream.
+        if let Ok(b'e') | Ok(b'E') = self.curr_byte() {
+            let has_exponent = match self.peek_byte(1) {
+                Ok(b'+') | Ok(b'-') => matches!(self.peek_byte(2), Ok(b'0'..=b'9')),
+                Ok(b'0'..=b'9') => true,
+                _ => false,
+            };
 
-                    match self.curr_byte()? {
-                        b'+' | b'-' => {
-                            self.advance(1);
-                            self.skip_digits();
-                        }
-                        b'0'..=b'9' => self.skip_digits(),
-                        _ => {
-                            return Err(Error::InvalidNumber(0));
-                        }
-                    }
+            if has_exponent {
+                self.advance(1);
+                if self.curr_byte_unchecked().is_sign() {
+                    self.advance(1);
                 }
+
+                self.skip_digits();
             }
         }
 
         let s = self.slice_back(start);
 
-        // Use the default f64 parser now.
-        if let Ok(n) = f64::from_str(s) {
-            // inf, nan, etc. are an error.
-            if n.is_finite() {
-                return Ok(n);
-            }
+        if let Some(n) = parse_simple_number(s) {
+            return Ok(n);
         }
 
-        Err(Error::InvalidNumber(0))
+        // Use the default f64 parser now.
+        // `inf`, `nan`, etc. never get here, so only an overflow can be infinite.
+        f64::from_str(s).map_err(|_| Error::InvalidNumber(0))
     }
 
     /// Parses number from a list of numbers.
@@ -117,6 +146,67 @@ impl<'a> Stream<'a> {
     }
 }
 
+/// Parses a short number without an exponent, like `-12.5`.
+///
+/// When the mantissa fits into 53 bits and there are no more than 22 fraction digits,
+/// both the mantissa and the power of ten are exact, so a single division
+/// produces a correctly rounded result. The same one that `f64::from_str` returns.
+///
+/// Returns `None` for all other numbers.
+fn parse_simple_number(text: &str) -> Option<f64> {
+    const POW10: [f64; 23] = [
+        1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+        1e17, 1e18, 1e19, 1e20, 1e21, 1e22,
+    ];
+    const MAX_MANTISSA: u64 = 1 << 53;
+
+    let mut bytes = text.as_bytes();
+    let mut negative = false;
+    if let Some(&c) = bytes.first() {
+        if c.is_sign() {
+            negative = c == b'-';
+            bytes = &bytes[1..];
+        }
+    }
+
+    let mut mantissa: u64 = 0;
+    let mut digits = 0;
+    let mut has_digits = false;
+    let mut fraction_digits = None;
+    for &c in bytes {
+        if c.is_digit() {
+            has_digits = true;
+
+            // Leading zeros do not affect the mantissa.
+            if mantissa != 0 || c != b'0' {
+                digits += 1;
+            }
+
+            // 19 digits always fit into `u64`.
+            if digits > 19 {
+                return None;
+            }
+
+            mantissa = mantissa * 10 + u64::from(c - b'0');
+            if let Some(ref mut n) = fraction_digits {
+                *n += 1;
+            }
+        } else if c == b'.' && fraction_digits.is_none() {
+            fraction_digits = Some(0);
+        } else {
+            return None;
+        }
+    }
+
+    let fraction_digits = fraction_digits.unwrap_or(0);
+    if !has_digits || mantissa > MAX_MANTISSA || fraction_digits >= POW10.len() {
+        return None;
+    }
+
+    let n = mantissa as f64 / POW10[fraction_digits];
+    Some(if negative { -n } else { n })
+}
+
 /// A pull-based [`<list-of-numbers>`] parser.
 ///
 /// # Examples
@@ -157,6 +247,69 @@ impl<'a> Iterator for NumberListParser<'a> {
             Some(v)
         }
     }
+
+    /// Returns a conservative estimate based on the remaining stream length.
+    ///
+    /// Every item takes at least one byte. A parsing error is an item too,
+    /// so `1x` yields two items.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.0.remaining();
+        (remaining.min(1), Some(remaining))
+    }
+}
+
+/// A pull-based parser for semicolon-separated numbers.
+///
+/// Used by animation attributes like `values` and `keyTimes`.
+/// Spaces around separators and a trailing semicolon are allowed.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::SemicolonNumberListParser;
+///
+/// let mut p = SemicolonNumberListParser::from("0;0.5;1");
+/// assert_eq!(p.next().unwrap().unwrap(), 0.0);
+/// assert_eq!(p.next().unwrap().unwrap(), 0.5);
+/// assert_eq!(p.next().unwrap().unwrap(), 1.0);
+/// assert_eq!(p.next().is_none(), true);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SemicolonNumberListParser<'a>(Stream<'a>);
+
+impl<'a> From<&'a str> for SemicolonNumberListParser<'a> {
+    #[inline]
+    fn from(v: &'a str) -> Self {
+        SemicolonNumberListParser(Stream::from(v))
+    }
+}
+
+impl<'a> Iterator for SemicolonNumberListParser<'a> {
+    type Item = Result<f64, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.skip_spaces();
+        if self.0.at_end() {
+            None
+        } else {
+            let v = self.0.parse_number().and_then(|n| {
+                self.0.skip_spaces();
+                if self.0.is_curr_byte_eq(b';') {
+                    self.0.advance(1);
+                } else if !self.0.at_end() {
+                    return Err(Error::UnexpectedData(self.0.calc_char_pos()));
+                }
+
+                Ok(n)
+            });
+
+            if v.is_err() {
+                self.0.jump_to_end();
+            }
+
+            Some(v)
+        }
+    }
 }
 
 #[rustfmt::skip]
@@ -174,6 +327,21 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_with_span_1() {
+        let mut s = Stream::from("  1.0e0 -.5,");
+        assert_eq!(s.parse_number_with_span().unwrap(), (1.0, "1.0e0"));
+        assert_eq!(s.parse_number_with_span().unwrap(), (-0.5, "-.5"));
+        assert_eq!(s.slice_tail(), ",");
+    }
+
+    #[test]
+    fn parse_with_span_2() {
+        let mut s = Stream::from(" q");
+        assert_eq!(s.parse_number_with_span().unwrap_err().to_string(),
+                   "invalid number at position 2");
+    }
+
     test_p!(parse_1,  "0", 0.0);
     test_p!(parse_2,  "1", 1.0);
     test_p!(parse_3,  "-1", -1.0);
@@ -197,7 +365,68 @@ mod tests {
     test_p!(parse_21, "12345678901234567890", 12345678901234567000.0);
     test_p!(parse_22, "0.", 0.0);
     test_p!(parse_23, "1.3e-2", 0.013);
-    // test_number!(parse_24, "1e", 1.0); // TODO: this
+    test_p!(parse_24, "1e", 1.0);
+    test_p!(parse_25, "1eL", 1.0);
+    test_p!(parse_26, "1e-", 1.0);
+    test_p!(parse_27, "1E+", 1.0);
+    test_p!(parse_28, "1e+2", 100.0);
+    test_p!(parse_29, "1ex", 1.0);
+
+    #[test]
+    fn simple_number_matches_std() {
+        let texts = [
+            "0", "-0", "+0", "0.", ".0", "-.0", "1", "-1", "0.1", "0.2", "0.3", "-0.7",
+            "123.456", "0.000001", "1.7976931348623157", "9007199254740992",
+            "9007199254740993", "4503599627370497.5", "0.1234567890123456789012",
+            "123456789012345678", "0000000000000000000000001.5", "3.14159265358979",
+            "2.2250738585072014", "-999999999999999.9", "20.3", "20.300000000000001",
+        ];
+
+        for text in texts.iter() {
+            let expected = text.parse::<f64>().unwrap();
+            let mut s = Stream::from(*text);
+            let n = s.parse_number().unwrap();
+            assert_eq!(n.to_bits(), expected.to_bits(), "{}", text);
+
+            if let Some(n) = super::parse_simple_number(text) {
+                assert_eq!(n.to_bits(), expected.to_bits(), "{}", text);
+            }
+        }
+    }
+
+    #[test]
+    fn simple_number_matches_std_random() {
+        // A simple LCG, so the test is deterministic.
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            state >> 33
+        };
+
+        for _ in 0..100_000 {
+            let int = next() % 1_000_000;
+            let frac = next() % 100_000_000;
+            let frac_len = (next() % 9) as usize;
+            let sign = if next() % 2 == 0 { "" } else { "-" };
+            let text = format!("{}{}.{:0>8}", sign, int, frac);
+            let text = &text[..text.len() - 8 + frac_len];
+
+            let expected = text.parse::<f64>().unwrap();
+            let n = super::parse_simple_number(text).unwrap();
+            assert_eq!(n.to_bits(), expected.to_bits(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn simple_number_fallback() {
+        assert_eq!(super::parse_simple_number("1e2"), None);
+        assert_eq!(super::parse_simple_number("."), None);
+        assert_eq!(super::parse_simple_number("-"), None);
+        assert_eq!(super::parse_simple_number(""), None);
+        assert_eq!(super::parse_simple_number("9007199254740993"), None);
+        assert_eq!(super::parse_simple_number("12345678901234567890"), None);
+        assert_eq!(super::parse_simple_number("0.00000000000000000000001"), None);
+    }
 
     macro_rules! test_p_err {
         ($name:ident, $text:expr) => (
@@ -218,4 +447,85 @@ mod tests {
     test_p_err!(parse_err_6, ".");
     test_p_err!(parse_err_7, "99999999e99999999");
     test_p_err!(parse_err_8, "-99999999e99999999");
+    test_p_err!(parse_err_9, "inf");
+    test_p_err!(parse_err_10, "-inf");
+    test_p_err!(parse_err_11, "infinity");
+    test_p_err!(parse_err_12, "nan");
+    test_p_err!(parse_err_13, "NaN");
+    test_p_err!(parse_err_14, "1e999");
+    test_p_err!(parse_err_15, "-1e999");
+
+    #[test]
+    fn parse_err_16() {
+        use std::str::FromStr;
+        assert_eq!(crate::Number::from_str("1e999").unwrap_err().to_string(),
+                   "invalid number at position 1");
+    }
+
+    #[test]
+    fn parse_err_17() {
+        let mut s = Stream::from("10 1e999");
+        assert_eq!(s.parse_list_number().unwrap(), 10.0);
+        assert_eq!(s.parse_list_number().unwrap_err().to_string(),
+                   "invalid number at position 4");
+    }
+
+    #[test]
+    fn parse_exponent_1() {
+        for &(text, tail) in &[("1e", "e"), ("1eL", "eL"), ("1e-", "e-"), ("1e+x", "e+x"), ("1e2L", "L")] {
+            let mut s = Stream::from(text);
+            s.parse_number().unwrap();
+            assert_eq!(s.slice_tail(), tail);
+        }
+    }
+
+    #[test]
+    fn size_hint_1() {
+        let mut p = crate::NumberListParser::from("1-2.5.5,4");
+        assert_eq!(p.size_hint(), (1, Some(9)));
+        assert_eq!(p.count(), 4);
+        p.by_ref().for_each(drop);
+        assert_eq!(p.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn size_hint_2() {
+        let p = crate::NumberListParser::from("1x");
+        assert_eq!(p.size_hint(), (1, Some(2)));
+        assert_eq!(p.count(), 2);
+    }
+
+    #[test]
+    fn semicolon_list_1() {
+        let list: Vec<f64> = crate::SemicolonNumberListParser::from(" 0 ; .25;1e1; ")
+            .collect::<Result<_, _>>().unwrap();
+        assert_eq!(list, vec![0.0, 0.25, 10.0]);
+    }
+
+    #[test]
+    fn semicolon_list_2() {
+        assert!(crate::SemicolonNumberListParser::from("  ").next().is_none());
+    }
+
+    #[test]
+    fn semicolon_list_err_1() {
+        let mut p = crate::SemicolonNumberListParser::from("0;1 2");
+        assert_eq!(p.next().unwrap().unwrap(), 0.0);
+        assert_eq!(p.next().unwrap().unwrap_err().to_string(), "unexpected data at position 5");
+        assert!(p.next().is_none());
+    }
+
+    #[test]
+    fn semicolon_list_err_2() {
+        let mut p = crate::SemicolonNumberListParser::from("0,1");
+        assert_eq!(p.next().unwrap().unwrap_err().to_string(), "unexpected data at position 2");
+        assert!(p.next().is_none());
+    }
+
+    #[test]
+    fn semicolon_list_err_3() {
+        let mut p = crate::SemicolonNumberListParser::from("0;;1");
+        assert_eq!(p.next().unwrap().unwrap(), 0.0);
+        assert_eq!(p.next().unwrap().unwrap_err().to_string(), "invalid number at position 3");
+    }
 }
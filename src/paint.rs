@@ -68,41 +68,39 @@ impl<'a> Paint<'a> {
     /// an owned value as a return type.
     #[allow(clippy::should_implement_trait)]
     pub fn from_str(text: &'a str) -> Result<Self, Error> {
-        let text = text.trim();
-        match text {
+        match text.trim() {
             "none" => Ok(Paint::None),
             "inherit" => Ok(Paint::Inherit),
             "currentColor" => Ok(Paint::CurrentColor),
             "context-fill" => Ok(Paint::ContextFill),
             "context-stroke" => Ok(Paint::ContextStroke),
-            _ => {
+            trimmed => {
                 let mut s = Stream::from(text);
+                s.skip_spaces();
                 if s.starts_with(b"url(") {
-                    match s.parse_func_iri() {
-                        Ok(link) => {
-                            s.skip_spaces();
-
-                            // get fallback
-                            if !s.at_end() {
-                                let fallback = s.slice_tail();
-                                match fallback {
-                                    "none" => Ok(Paint::FuncIRI(link, Some(PaintFallback::None))),
-                                    "currentColor" => {
-                                        Ok(Paint::FuncIRI(link, Some(PaintFallback::CurrentColor)))
-                                    }
-                                    _ => {
-                                        let color = Color::from_str(fallback)?;
-                                        Ok(Paint::FuncIRI(link, Some(PaintFallback::Color(color))))
-                                    }
-                                }
-                            } else {
-                                Ok(Paint::FuncIRI(link, None))
-                            }
-                        }
-                        Err(_) => Err(Error::InvalidValue),
+                    let link = s.parse_func_iri().map_err(|_| Error::InvalidValue)?;
+                    s.skip_spaces();
+
+                    // get fallback
+                    let fallback = if s.at_end() {
+                        None
+                    } else if s.try_consume_keyword("none") {
+                        Some(PaintFallback::None)
+                    } else if s.try_consume_keyword("currentColor") {
+                        Some(PaintFallback::CurrentColor)
+                    } else {
+                        Some(PaintFallback::Color(s.parse_color()?))
+                    };
+
+                    // Only a single fallback is allowed.
+                    s.skip_spaces();
+                    if !s.at_end() {
+                        return Err(Error::UnexpectedData(s.calc_char_pos()));
                     }
+
+                    Ok(Paint::FuncIRI(link, fallback))
                 } else {
-                    match Color::from_str(text) {
+                    match Color::from_str(trimmed) {
                         Ok(c) => Ok(Paint::Color(c)),
                         Err(_) => Err(Error::InvalidValue),
                     }
@@ -110,6 +108,56 @@ impl<'a> Paint<'a> {
             }
         }
     }
+
+    /// Converts the `Paint` into an `OwnedPaint` by cloning the link.
+    pub fn into_owned(self) -> OwnedPaint {
+        match self {
+            Paint::None => OwnedPaint::None,
+            Paint::Inherit => OwnedPaint::Inherit,
+            Paint::CurrentColor => OwnedPaint::CurrentColor,
+            Paint::Color(c) => OwnedPaint::Color(c),
+            Paint::FuncIRI(link, fallback) => OwnedPaint::FuncIRI(link.to_string(), fallback),
+            Paint::ContextFill => OwnedPaint::ContextFill,
+            Paint::ContextStroke => OwnedPaint::ContextStroke,
+        }
+    }
+}
+
+/// An owned version of the [`Paint`](enum.Paint.html) type.
+///
+/// Can be stored past the lifetime of the parsed string.
+#[allow(missing_docs)]
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum OwnedPaint {
+    None,
+    Inherit,
+    CurrentColor,
+    Color(Color),
+    FuncIRI(String, Option<PaintFallback>),
+    ContextFill,
+    ContextStroke,
+}
+
+impl OwnedPaint {
+    /// Returns a borrowed `Paint`.
+    pub fn as_paint(&self) -> Paint<'_> {
+        match *self {
+            OwnedPaint::None => Paint::None,
+            OwnedPaint::Inherit => Paint::Inherit,
+            OwnedPaint::CurrentColor => Paint::CurrentColor,
+            OwnedPaint::Color(c) => Paint::Color(c),
+            OwnedPaint::FuncIRI(ref link, fallback) => Paint::FuncIRI(link, fallback),
+            OwnedPaint::ContextFill => Paint::ContextFill,
+            OwnedPaint::ContextStroke => Paint::ContextStroke,
+        }
+    }
+}
+
+impl<'a> From<Paint<'a>> for OwnedPaint {
+    #[inline]
+    fn from(paint: Paint<'a>) -> Self {
+        paint.into_owned()
+    }
 }
 
 #[rustfmt::skip]
@@ -135,6 +183,10 @@ mod tests {
     test!(parse_7, " url(#qwe) none ", Paint::FuncIRI("qwe", Some(PaintFallback::None)));
     test!(parse_8, " url(#qwe) currentColor ", Paint::FuncIRI("qwe", Some(PaintFallback::CurrentColor)));
     test!(parse_9, " url(#qwe) red ", Paint::FuncIRI("qwe", Some(PaintFallback::Color(Color::red()))));
+    test!(parse_10, "context-fill", Paint::ContextFill);
+    test!(parse_12, "url(#g)  none  ", Paint::FuncIRI("g", Some(PaintFallback::None)));
+    test!(parse_13, "url(#g) #ff0000", Paint::FuncIRI("g", Some(PaintFallback::Color(Color::red()))));
+    test!(parse_11, " context-stroke ", Paint::ContextStroke);
 
     macro_rules! test_err {
         ($name:ident, $text:expr, $result:expr) => (
@@ -147,6 +199,31 @@ mod tests {
 
     test_err!(parse_err_1, "qwe", "invalid value");
     test_err!(parse_err_2, "red icc-color(acmecmyk, 0.11, 0.48, 0.83, 0.00)", "invalid value");
-    // TODO: this
-//    test_err!(parse_err_3, "url(#qwe) red icc-color(acmecmyk, 0.11, 0.48, 0.83, 0.00)", "invalid color at 1:15");
+    test_err!(parse_err_3, "url(#qwe) red icc-color(acmecmyk, 0.11, 0.48, 0.83, 0.00)", "unexpected data at position 15");
+    test_err!(parse_err_4, "Context-Fill", "invalid value");
+    // The spec allows only `none` and `<color>` as a fallback.
+    test_err!(parse_err_5, "url(#g) context-stroke", "unexpected data at position 9");
+    test_err!(parse_err_6, "url(#g) context-fill", "unexpected data at position 9");
+    test_err!(parse_err_7, "url(#g) red garbage", "unexpected data at position 13");
+    test_err!(parse_err_8, "url(#g) none red", "unexpected data at position 14");
+    test_err!(parse_err_9, " url(#g) currentColor none", "unexpected data at position 23");
+    test_err!(parse_err_10, "url(#g) nonered", "unexpected data at position 9");
+
+    #[test]
+    fn into_owned_1() {
+        let owned = {
+            let text = String::from("url(#g) red");
+            Paint::from_str(&text).unwrap().into_owned()
+        };
+
+        assert_eq!(owned, OwnedPaint::FuncIRI("g".to_string(), Some(PaintFallback::Color(Color::red()))));
+        assert_eq!(owned.as_paint(), Paint::from_str("url(#g) red").unwrap());
+    }
+
+    #[test]
+    fn into_owned_2() {
+        let paint = Paint::from_str("context-stroke").unwrap();
+        assert_eq!(OwnedPaint::from(paint), OwnedPaint::ContextStroke);
+        assert_eq!(OwnedPaint::from(paint).as_paint(), paint);
+    }
 }
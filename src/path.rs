@@ -7,6 +7,7 @@ use crate::{Error, Stream};
 /// But you still can change points or make segment relative or absolute.
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PathSegment {
     MoveTo {
         abs: bool,
@@ -69,6 +70,182 @@ pub enum PathSegment {
     },
 }
 
+impl PathSegment {
+    /// Constructs a new `EllipticalArc` segment from untrusted values.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidValue` when any of the numbers is not finite
+    ///   or when `rx` or `ry` is negative.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_arc(
+        abs: bool,
+        rx: f64,
+        ry: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    ) -> Result<PathSegment, Error> {
+        let is_finite = rx.is_finite()
+            && ry.is_finite()
+            && x_axis_rotation.is_finite()
+            && x.is_finite()
+            && y.is_finite();
+
+        if !is_finite || rx < 0.0 || ry < 0.0 {
+            return Err(Error::InvalidValue);
+        }
+
+        Ok(PathSegment::EllipticalArc {
+            abs,
+            rx,
+            ry,
+            x_axis_rotation,
+            large_arc,
+            sweep,
+            x,
+            y,
+        })
+    }
+
+    /// Returns all coordinate pairs of the segment in order.
+    ///
+    /// Control points come first and the end point is always the last one.
+    /// `HorizontalLineTo` and `VerticalLineTo` have only one coordinate,
+    /// so the missing one is set to `None`.
+    /// Arc radii, rotation and flags are not points and will not be returned.
+    ///
+    /// Returns an empty list for `ClosePath`.
+    pub fn control_points(&self) -> Vec<(Option<f64>, Option<f64>)> {
+        match *self {
+            PathSegment::MoveTo { x, y, .. }
+            | PathSegment::LineTo { x, y, .. }
+            | PathSegment::SmoothQuadratic { x, y, .. }
+            | PathSegment::EllipticalArc { x, y, .. } => vec![(Some(x), Some(y))],
+            PathSegment::HorizontalLineTo { x, .. } => vec![(Some(x), None)],
+            PathSegment::VerticalLineTo { y, .. } => vec![(None, Some(y))],
+            PathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+                ..
+            } => vec![
+                (Some(x1), Some(y1)),
+                (Some(x2), Some(y2)),
+                (Some(x), Some(y)),
+            ],
+            PathSegment::SmoothCurveTo { x2, y2, x, y, .. } => {
+                vec![(Some(x2), Some(y2)), (Some(x), Some(y))]
+            }
+            PathSegment::Quadratic { x1, y1, x, y, .. } => {
+                vec![(Some(x1), Some(y1)), (Some(x), Some(y))]
+            }
+            PathSegment::ClosePath { .. } => Vec::new(),
+        }
+    }
+
+    /// Sets the segment end point.
+    ///
+    /// `HorizontalLineTo` will use only `x` and `VerticalLineTo` only `y`.
+    /// Does nothing for `ClosePath`.
+    pub fn set_endpoint(&mut self, new_x: f64, new_y: f64) {
+        match self {
+            PathSegment::MoveTo { x, y, .. }
+            | PathSegment::LineTo { x, y, .. }
+            | PathSegment::CurveTo { x, y, .. }
+            | PathSegment::SmoothCurveTo { x, y, .. }
+            | PathSegment::Quadratic { x, y, .. }
+            | PathSegment::SmoothQuadratic { x, y, .. }
+            | PathSegment::EllipticalArc { x, y, .. } => {
+                *x = new_x;
+                *y = new_y;
+            }
+            PathSegment::HorizontalLineTo { x, .. } => *x = new_x,
+            PathSegment::VerticalLineTo { y, .. } => *y = new_y,
+            PathSegment::ClosePath { .. } => {}
+        }
+    }
+
+    /// Converts a quadratic curve into an absolute `CurveTo`.
+    ///
+    /// `start` is the current point, `x1`/`y1` is the control point
+    /// and `x`/`y` is the end point. All coordinates must be absolute.
+    pub fn quad_to_cubic(start: (f64, f64), x1: f64, y1: f64, x: f64, y: f64) -> PathSegment {
+        PathSegment::CurveTo {
+            abs: true,
+            x1: start.0 + 2.0 / 3.0 * (x1 - start.0),
+            y1: start.1 + 2.0 / 3.0 * (y1 - start.1),
+            x2: x + 2.0 / 3.0 * (x1 - x),
+            y2: y + 2.0 / 3.0 * (y1 - y),
+            x,
+            y,
+        }
+    }
+
+    /// Returns `EllipticalArc` radii scaled up to span the arc end points.
+    ///
+    /// `start` is the current point, i.e. the end of the previous segment.
+    ///
+    /// See [Correction of out-of-range radii](https://www.w3.org/TR/SVG11/implnote.html#ArcCorrectionOutOfRangeRadii).
+    ///
+    /// Returns `None` for other segments.
+    pub fn corrected_arc_radii(&self, start: (f64, f64)) -> Option<(f64, f64)> {
+        match *self {
+            PathSegment::EllipticalArc {
+                abs,
+                rx,
+                ry,
+                x_axis_rotation,
+                x,
+                y,
+                ..
+            } => {
+                let end = if abs {
+                    (x, y)
+                } else {
+                    (start.0 + x, start.1 + y)
+                };
+
+                Some(correct_arc_radii(start, end, rx, ry, x_axis_rotation))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn correct_arc_radii(
+    start: (f64, f64),
+    end: (f64, f64),
+    rx: f64,
+    ry: f64,
+    x_axis_rotation: f64,
+) -> (f64, f64) {
+    let rx = rx.abs();
+    let ry = ry.abs();
+    if rx == 0.0 || ry == 0.0 {
+        return (rx, ry);
+    }
+
+    let (sin, cos) = x_axis_rotation.to_radians().sin_cos();
+    let dx2 = (start.0 - end.0) / 2.0;
+    let dy2 = (start.1 - end.1) / 2.0;
+    let x1 = cos * dx2 + sin * dy2;
+    let y1 = -sin * dx2 + cos * dy2;
+
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        let s = lambda.sqrt();
+        (rx * s, ry * s)
+    } else {
+        (rx, ry)
+    }
+}
+
 /// A pull-based [path data] parser.
 ///
 /// # Errors
@@ -118,6 +295,19 @@ impl<'a> From<&'a str> for PathParser<'a> {
     }
 }
 
+impl<'a> PathParser<'a> {
+    /// Enables or disables lenient whitespace handling.
+    ///
+    /// The SVG spec allows only `' ' \n \r \t` as whitespace, which is the default.
+    /// But some real-world files contain other whitespace, like a non-breaking space
+    /// or a form feed. In lenient mode any Unicode whitespace will be skipped.
+    #[inline]
+    pub fn lenient_whitespace(mut self, enabled: bool) -> Self {
+        self.stream.set_lenient_spaces(enabled);
+        self
+    }
+}
+
 impl<'a> Iterator for PathParser<'a> {
     type Item = Result<PathSegment, Error>;
 
@@ -235,11 +425,12 @@ fn next_impl(s: &mut Stream, prev_cmd: &mut Option<u8>) -> Result<PathSegment, E
             y: s.parse_list_number()?,
         },
         b'a' => {
-            // TODO: radius cannot be negative
+            // 'If rx or ry have negative signs, these are dropped;
+            // the absolute value is used instead.'
             PathSegment::EllipticalArc {
                 abs: absolute,
-                rx: s.parse_list_number()?,
-                ry: s.parse_list_number()?,
+                rx: s.parse_list_number()?.abs(),
+                ry: s.parse_list_number()?.abs(),
                 x_axis_rotation: s.parse_list_number()?,
                 large_arc: parse_flag(s)?,
                 sweep: parse_flag(s)?,
@@ -413,6 +604,83 @@ mod tests {
         }
     );
 
+    test!(arc_to_negative_radii, "M 10 20 A -5 -5 -30 0 0 10 10",
+        PathSegment::MoveTo { abs: true, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: true,
+            rx: 5.0, ry: 5.0,
+            x_axis_rotation: -30.0,
+            large_arc: false, sweep: false,
+            x: 10.0, y: 10.0
+        }
+    );
+
+    test!(arc_to_packed_flags_1, "m 10 20 a25 25 0 0115 20",
+        PathSegment::MoveTo { abs: false, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: false,
+            rx: 25.0, ry: 25.0,
+            x_axis_rotation: 0.0,
+            large_arc: false, sweep: true,
+            x: 15.0, y: 20.0
+        }
+    );
+
+    test!(arc_to_packed_flags_negative_1, "m 10 20 a25 25 0 00-15 20",
+        PathSegment::MoveTo { abs: false, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: false,
+            rx: 25.0, ry: 25.0,
+            x_axis_rotation: 0.0,
+            large_arc: false, sweep: false,
+            x: -15.0, y: 20.0
+        }
+    );
+
+    test!(arc_to_packed_flags_negative_2, "m 10 20 a25 25 0 1,1-15-20",
+        PathSegment::MoveTo { abs: false, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: false,
+            rx: 25.0, ry: 25.0,
+            x_axis_rotation: 0.0,
+            large_arc: true, sweep: true,
+            x: -15.0, y: -20.0
+        }
+    );
+
+    test!(arc_to_packed_flags_2, "M 10 20 A 5 5 0 00 10 10",
+        PathSegment::MoveTo { abs: true, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: true,
+            rx: 5.0, ry: 5.0,
+            x_axis_rotation: 0.0,
+            large_arc: false, sweep: false,
+            x: 10.0, y: 10.0
+        }
+    );
+
+    test!(arc_to_packed_flags_3, "M 10 20 A 5 5 0 01.5 10",
+        PathSegment::MoveTo { abs: true, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: true,
+            rx: 5.0, ry: 5.0,
+            x_axis_rotation: 0.0,
+            large_arc: false, sweep: true,
+            x: 0.5, y: 10.0
+        }
+    );
+
+    test!(arc_to_packed_flags_4, "M 10 20 A 5 5 0 1110.5.5",
+        PathSegment::MoveTo { abs: true, x: 10.0, y: 20.0 },
+        PathSegment::EllipticalArc {
+            abs: true,
+            rx: 5.0, ry: 5.0,
+            x_axis_rotation: 0.0,
+            large_arc: true, sweep: true,
+            x: 10.5, y: 0.5
+        }
+    );
+
     test!(separator_1, "M 10 20 L 5 15 C 10 20 30 40 50 60",
         PathSegment::MoveTo { abs: true, x: 10.0, y: 20.0 },
         PathSegment::LineTo { abs: true, x: 5.0, y: 15.0 },
@@ -571,6 +839,127 @@ mod tests {
         PathSegment::ClosePath { abs: true },
         PathSegment::HorizontalLineTo { abs: true, x: 10.0 }
     );
+
+    #[test]
+    fn lenient_whitespace_1() {
+        let text = "\u{a0}M 10\u{a0}20\u{c}L 30 40\u{a0}";
+
+        let mut s = PathParser::from(text);
+        assert!(s.next().unwrap().is_err());
+
+        let mut s = PathParser::from(text).lenient_whitespace(true);
+        assert_eq!(s.next().unwrap().unwrap(), PathSegment::MoveTo { abs: true, x: 10.0, y: 20.0 });
+        assert_eq!(s.next().unwrap().unwrap(), PathSegment::LineTo { abs: true, x: 30.0, y: 40.0 });
+        assert!(s.next().is_none());
+    }
+
+    #[test]
+    fn try_arc_1() {
+        assert_eq!(
+            PathSegment::try_arc(true, 5.0, 10.0, 30.0, false, true, 1.0, 2.0).unwrap(),
+            PathSegment::EllipticalArc {
+                abs: true, rx: 5.0, ry: 10.0, x_axis_rotation: 30.0,
+                large_arc: false, sweep: true, x: 1.0, y: 2.0
+            }
+        );
+    }
+
+    #[test]
+    fn try_arc_err_1() {
+        assert_eq!(PathSegment::try_arc(true, 5.0, 10.0, 0.0, false, false, f64::NAN, 2.0),
+                   Err(Error::InvalidValue));
+        assert_eq!(PathSegment::try_arc(true, 5.0, f64::INFINITY, 0.0, false, false, 1.0, 2.0),
+                   Err(Error::InvalidValue));
+    }
+
+    #[test]
+    fn try_arc_err_2() {
+        assert_eq!(PathSegment::try_arc(true, -5.0, 10.0, 0.0, false, false, 1.0, 2.0),
+                   Err(Error::InvalidValue));
+    }
+
+    #[test]
+    fn quad_to_cubic_1() {
+        let start = (0.0, 0.0);
+        let (cx, cy, x, y) = (30.0, 60.0, 90.0, 0.0);
+        let seg = PathSegment::quad_to_cubic(start, cx, cy, x, y);
+        assert_eq!(seg, PathSegment::CurveTo { abs: true, x1: 20.0, y1: 40.0, x2: 50.0, y2: 40.0, x: 90.0, y: 0.0 });
+
+        if let PathSegment::CurveTo { x1, y1, x2, y2, .. } = seg {
+            let quad_mid = (0.25 * start.0 + 0.5 * cx + 0.25 * x, 0.25 * start.1 + 0.5 * cy + 0.25 * y);
+            let cubic_mid = ((start.0 + 3.0 * x1 + 3.0 * x2 + x) / 8.0, (start.1 + 3.0 * y1 + 3.0 * y2 + y) / 8.0);
+            assert!((quad_mid.0 - cubic_mid.0).abs() < 1e-9);
+            assert!((quad_mid.1 - cubic_mid.1).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn corrected_arc_radii_1() {
+        // The distance between points is 20, so radii must be at least 10.
+        let seg = PathSegment::EllipticalArc {
+            abs: true, rx: 5.0, ry: 5.0, x_axis_rotation: 0.0,
+            large_arc: false, sweep: true, x: 20.0, y: 0.0
+        };
+        assert_eq!(seg.corrected_arc_radii((0.0, 0.0)), Some((10.0, 10.0)));
+    }
+
+    #[test]
+    fn corrected_arc_radii_2() {
+        let seg = PathSegment::EllipticalArc {
+            abs: false, rx: 5.0, ry: 2.5, x_axis_rotation: 0.0,
+            large_arc: false, sweep: true, x: 20.0, y: 0.0
+        };
+        assert_eq!(seg.corrected_arc_radii((10.0, 10.0)), Some((10.0, 5.0)));
+    }
+
+    #[test]
+    fn corrected_arc_radii_3() {
+        let seg = PathSegment::EllipticalArc {
+            abs: true, rx: 20.0, ry: 20.0, x_axis_rotation: 0.0,
+            large_arc: false, sweep: true, x: 20.0, y: 0.0
+        };
+        assert_eq!(seg.corrected_arc_radii((0.0, 0.0)), Some((20.0, 20.0)));
+
+        let seg = PathSegment::LineTo { abs: true, x: 20.0, y: 0.0 };
+        assert_eq!(seg.corrected_arc_radii((0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn control_points_1() {
+        let seg = PathSegment::CurveTo { abs: true, x1: 1.0, y1: 2.0, x2: 3.0, y2: 4.0, x: 5.0, y: 6.0 };
+        assert_eq!(seg.control_points(), vec![
+            (Some(1.0), Some(2.0)),
+            (Some(3.0), Some(4.0)),
+            (Some(5.0), Some(6.0)),
+        ]);
+    }
+
+    #[test]
+    fn control_points_2() {
+        let seg = PathSegment::HorizontalLineTo { abs: true, x: 10.0 };
+        assert_eq!(seg.control_points(), vec![(Some(10.0), None)]);
+    }
+
+    #[test]
+    fn control_points_3() {
+        let seg = PathSegment::EllipticalArc {
+            abs: true, rx: 5.0, ry: 5.0, x_axis_rotation: 0.0,
+            large_arc: false, sweep: true, x: 10.0, y: 20.0,
+        };
+        assert_eq!(seg.control_points(), vec![(Some(10.0), Some(20.0))]);
+        assert!(PathSegment::ClosePath { abs: true }.control_points().is_empty());
+    }
+
+    #[test]
+    fn set_endpoint_1() {
+        let mut seg = PathSegment::Quadratic { abs: false, x1: 1.0, y1: 2.0, x: 3.0, y: 4.0 };
+        seg.set_endpoint(10.0, 20.0);
+        assert_eq!(seg, PathSegment::Quadratic { abs: false, x1: 1.0, y1: 2.0, x: 10.0, y: 20.0 });
+
+        let mut seg = PathSegment::VerticalLineTo { abs: true, y: 1.0 };
+        seg.set_endpoint(10.0, 20.0);
+        assert_eq!(seg, PathSegment::VerticalLineTo { abs: true, y: 20.0 });
+    }
 }
 
 /// Representation of a simple path segment.
@@ -828,6 +1217,9 @@ impl<'a> Iterator for SimplifyingPathParser<'a> {
                     y += self.prev_y;
                 }
 
+                let (rx, ry) =
+                    correct_arc_radii((self.prev_x, self.prev_y), (x, y), rx, ry, x_axis_rotation);
+
                 let svg_arc = kurbo::SvgArc {
                     from: kurbo::Point::new(self.prev_x, self.prev_y),
                     to: kurbo::Point::new(x, y),
@@ -1041,4 +1433,13 @@ mod simple_tests {
             panic!("invalid type");
         }
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let segments: Vec<PathSegment> = PathParser::from("M10 20 L30 40 A5 5 30 1 0 50 60 Z")
+            .collect::<Result<_, _>>().unwrap();
+        let json = serde_json::to_string(&segments).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<PathSegment>>(&json).unwrap(), segments);
+    }
 }
@@ -1,4 +1,40 @@
-use crate::Stream;
+use crate::{Error, Stream};
+
+/// Parses a [`<list-of-points>`] and reports any malformed data.
+///
+/// Unlike [`PointsParser`], which stops silently, this function returns an error
+/// on an invalid number or an odd number of coordinates.
+///
+/// # Errors
+///
+/// - `InvalidNumber` on an invalid coordinate.
+/// - `UnexpectedEndOfStream` when the last point has no `y` coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::parse_points_strict;
+///
+/// assert_eq!(parse_points_strict("10 20 30 40").unwrap(), vec![(10.0, 20.0), (30.0, 40.0)]);
+/// assert!(parse_points_strict("10 20 30").is_err());
+/// ```
+///
+/// [`<list-of-points>`]: https://www.w3.org/TR/SVG11/shapes.html#PointsBNF
+/// [`PointsParser`]: struct.PointsParser.html
+pub fn parse_points_strict(text: &str) -> Result<Vec<(f64, f64)>, Error> {
+    let mut s = Stream::from(text);
+    let mut points = Vec::new();
+
+    s.skip_spaces();
+    while !s.at_end() {
+        let x = s.parse_list_number()?;
+        let y = s.parse_list_number()?;
+        points.push((x, y));
+        s.skip_spaces();
+    }
+
+    Ok(points)
+}
 
 /// A pull-based [`<list-of-points>`] parser.
 ///
@@ -37,6 +73,17 @@ impl<'a> From<&'a str> for PointsParser<'a> {
     }
 }
 
+impl<'a> PointsParser<'a> {
+    /// Parses all remaining points into the provided buffer.
+    ///
+    /// The buffer is cleared first, so it can be reused across parsers
+    /// without a new allocation each time.
+    pub fn parse_into(&mut self, out: &mut Vec<(f64, f64)>) {
+        out.clear();
+        out.extend(self);
+    }
+}
+
 impl<'a> Iterator for PointsParser<'a> {
     type Item = (f64, f64);
 
@@ -57,6 +104,13 @@ impl<'a> Iterator for PointsParser<'a> {
             Some((x, y))
         }
     }
+
+    /// Returns a conservative estimate based on the remaining stream length.
+    ///
+    /// Every point but the last one takes at least four bytes, including separators.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some((self.0.remaining() + 1) / 4))
+    }
 }
 
 #[rustfmt::skip]
@@ -79,4 +133,40 @@ mod tests {
         assert_eq!(parser.next().unwrap(), (30.0, 40.0));
         assert!(parser.next().is_none());
     }
+
+    #[test]
+    fn parse_strict_1() {
+        assert_eq!(parse_points_strict(" 10,20 30 40 ").unwrap(), vec![(10.0, 20.0), (30.0, 40.0)]);
+        assert_eq!(parse_points_strict("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn parse_strict_err_1() {
+        assert_eq!(PointsParser::from("10 20 30").count(), 1);
+        assert_eq!(parse_points_strict("10 20 30").unwrap_err(), Error::UnexpectedEndOfStream);
+    }
+
+    #[test]
+    fn parse_strict_err_2() {
+        assert_eq!(parse_points_strict("10 20 x 40").unwrap_err().to_string(),
+                   "invalid number at position 7");
+    }
+
+    #[test]
+    fn size_hint_1() {
+        let p = PointsParser::from("1 2-3-4");
+        assert_eq!(p.size_hint(), (0, Some(2)));
+        assert_eq!(p.count(), 2);
+        assert_eq!(PointsParser::from("").size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn parse_into_1() {
+        let mut buf = Vec::new();
+        PointsParser::from("10 20 30 40").parse_into(&mut buf);
+        assert_eq!(buf, vec![(10.0, 20.0), (30.0, 40.0)]);
+
+        PointsParser::from("50 60").parse_into(&mut buf);
+        assert_eq!(buf, vec![(50.0, 60.0)]);
+    }
 }
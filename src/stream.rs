@@ -22,13 +22,6 @@ pub(crate) trait ByteExt {
     /// `[ \r\n\t]`
     fn is_space(&self) -> bool;
 
-    fn is_quote(&self) -> bool;
-
-    /// Checks if a byte is an ASCII char.
-    ///
-    /// `[A-Za-z]`
-    fn is_letter(&self) -> bool;
-
     /// Checks if a byte is an ASCII ident char.
     fn is_ascii_ident(&self) -> bool;
 }
@@ -54,16 +47,6 @@ impl ByteExt for u8 {
         matches!(*self, b' ' | b'\t' | b'\n' | b'\r')
     }
 
-    #[inline]
-    fn is_quote(&self) -> bool {
-        matches!(*self, b'\'' | b'"')
-    }
-
-    #[inline]
-    fn is_letter(&self) -> bool {
-        matches!(*self, b'A'..=b'Z' | b'a'..=b'z')
-    }
-
     #[inline]
     fn is_ascii_ident(&self) -> bool {
         matches!(*self, b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' | b'-' | b'_')
@@ -111,12 +94,17 @@ impl CharExt for char {
 pub struct Stream<'a> {
     text: &'a str,
     pos: usize,
+    lenient_spaces: bool,
 }
 
 impl<'a> From<&'a str> for Stream<'a> {
     #[inline]
     fn from(text: &'a str) -> Self {
-        Stream { text, pos: 0 }
+        Stream {
+            text,
+            pos: 0,
+            lenient_spaces: false,
+        }
     }
 }
 
@@ -158,12 +146,9 @@ impl<'a> Stream<'a> {
     ///
     /// Any [`pos()`] value larger than original text length indicates stream end.
     ///
-    /// Accessing stream after reaching end via safe methods will produce
+    /// Accessing stream after reaching end will produce
     /// an `UnexpectedEndOfStream` error.
     ///
-    /// Accessing stream after reaching end via *_unchecked methods will produce
-    /// a Rust's bound checking error.
-    ///
     /// [`pos()`]: #method.pos
     #[inline]
     pub fn at_end(&self) -> bool {
@@ -184,8 +169,9 @@ impl<'a> Stream<'a> {
         Ok(self.curr_byte_unchecked())
     }
 
+    /// Returns an iterator over the remaining characters.
     #[inline]
-    pub fn chars(&self) -> std::str::Chars<'a> {
+    pub(crate) fn chars(&self) -> std::str::Chars<'a> {
         self.text[self.pos..].chars()
     }
 
@@ -195,7 +181,7 @@ impl<'a> Stream<'a> {
     ///
     /// - if the current position is after the end of the data
     #[inline]
-    pub fn curr_byte_unchecked(&self) -> u8 {
+    pub(crate) fn curr_byte_unchecked(&self) -> u8 {
         self.text.as_bytes()[self.pos]
     }
 
@@ -211,6 +197,18 @@ impl<'a> Stream<'a> {
         }
     }
 
+    /// Checks that current byte is equal to one of the provided.
+    ///
+    /// Returns `false` if no bytes left.
+    #[inline]
+    pub fn is_curr_byte_one_of(&self, bytes: &[u8]) -> bool {
+        if !self.at_end() {
+            bytes.contains(&self.curr_byte_unchecked())
+        } else {
+            false
+        }
+    }
+
     /// Returns a next byte from a current stream position.
     ///
     /// # Errors
@@ -225,38 +223,108 @@ impl<'a> Stream<'a> {
         Ok(self.text.as_bytes()[self.pos + 1])
     }
 
+    /// Returns a byte `n` bytes after the current stream position.
+    ///
+    /// `peek_byte(0)` is the same as `curr_byte()`.
+    ///
+    /// # Errors
+    ///
+    /// - `UnexpectedEndOfStream`
+    #[inline]
+    pub fn peek_byte(&self, n: usize) -> Result<u8, Error> {
+        match self.pos.checked_add(n) {
+            Some(idx) if idx < self.text.len() => Ok(self.text.as_bytes()[idx]),
+            _ => Err(Error::UnexpectedEndOfStream),
+        }
+    }
+
+    /// Returns the number of bytes left in the stream.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.text.len().saturating_sub(self.pos)
+    }
+
     /// Advances by `n` bytes.
     #[inline]
-    pub fn advance(&mut self, n: usize) {
+    pub(crate) fn advance(&mut self, n: usize) {
         debug_assert!(self.pos + n <= self.text.len());
         self.pos += n;
     }
 
+    /// Enables or disables lenient whitespace handling.
+    ///
+    /// Disabled by default.
+    ///
+    /// See [`skip_spaces()`] for details.
+    ///
+    /// [`skip_spaces()`]: #method.skip_spaces
+    #[inline]
+    pub fn set_lenient_spaces(&mut self, enabled: bool) {
+        self.lenient_spaces = enabled;
+    }
+
     /// Skips whitespaces.
     ///
     /// Accepted values: `' ' \n \r \t`.
+    ///
+    /// When lenient whitespace handling is enabled, any Unicode whitespace
+    /// will be skipped as well. Like a non-breaking space or a form feed.
     pub fn skip_spaces(&mut self) {
         while !self.at_end() && self.curr_byte_unchecked().is_space() {
             self.advance(1);
         }
+
+        if self.lenient_spaces {
+            for c in self.chars() {
+                if c.is_whitespace() {
+                    self.advance(c.len_utf8());
+                } else {
+                    break;
+                }
+            }
+        }
     }
 
     /// Checks that the stream starts with a selected text.
     ///
     /// We are using `&[u8]` instead of `&str` for performance reasons.
     #[inline]
-    pub fn starts_with(&self, text: &[u8]) -> bool {
+    pub(crate) fn starts_with(&self, text: &[u8]) -> bool {
         self.text.as_bytes()[self.pos..].starts_with(text)
     }
 
+    /// Consumes a keyword if the stream starts with it.
+    ///
+    /// The keyword must be followed by a word boundary,
+    /// so `none` will not be matched inside `nonesuch`.
+    ///
+    /// Returns `true` when the keyword was consumed.
+    pub fn try_consume_keyword(&mut self, keyword: &str) -> bool {
+        if !self.starts_with(keyword.as_bytes()) {
+            return false;
+        }
+
+        let end = self.pos + keyword.len();
+        if let Some(c) = self.text.as_bytes().get(end) {
+            if c.is_ascii_ident() {
+                return false;
+            }
+        }
+
+        self.pos = end;
+        true
+    }
+
     /// Consumes current byte if it's equal to the provided byte.
     ///
+    /// Only ASCII bytes can be consumed, so the stream never stops inside a char.
+    ///
     /// # Errors
     ///
     /// - `InvalidChar`
     /// - `UnexpectedEndOfStream`
     pub fn consume_byte(&mut self, c: u8) -> Result<(), Error> {
-        if self.curr_byte()? != c {
+        if self.curr_byte()? != c || !c.is_ascii() {
             return Err(Error::InvalidChar(
                 vec![self.curr_byte_unchecked(), c],
                 self.calc_char_pos(),
@@ -318,31 +386,33 @@ impl<'a> Stream<'a> {
     /// - `UnexpectedEndOfStream`
     /// - `InvalidValue`
     pub fn parse_quoted_string(&mut self) -> Result<&'a str, Error> {
-        // Check for opening quote.
-        let quote = self.curr_byte()?;
+        if self.at_end() {
+            return Err(Error::UnexpectedEndOfStream);
+        }
 
-        if quote != b'\'' && quote != b'"' {
+        // Check for opening quote.
+        if !self.is_curr_byte_one_of(b"'\"") {
             return Err(Error::InvalidValue);
         }
 
-        let mut prev = quote;
+        let quote = self.curr_byte_unchecked();
         self.advance(1);
 
         let start = self.pos();
 
+        // Advance until the closing quote.
         while !self.at_end() {
             let curr = self.curr_byte_unchecked();
-
-            // Advance until the closing quote.
             if curr == quote {
-                // Check for escaped quote.
-                if prev != b'\\' {
-                    break;
-                }
+                break;
             }
 
-            prev = curr;
             self.advance(1);
+
+            // Skip an escaped byte, which can be a quote or a backslash.
+            if curr == b'\\' && !self.at_end() {
+                self.advance(1);
+            }
         }
 
         let value = self.slice_back(start);
@@ -359,7 +429,7 @@ impl<'a> Stream<'a> {
     ///
     /// - `InvalidChar`
     /// - `UnexpectedEndOfStream`
-    pub fn consume_string(&mut self, text: &[u8]) -> Result<(), Error> {
+    pub(crate) fn consume_string(&mut self, text: &[u8]) -> Result<(), Error> {
         if self.at_end() {
             return Err(Error::UnexpectedEndOfStream);
         }
@@ -387,7 +457,7 @@ impl<'a> Stream<'a> {
     /// Consumes bytes by the predicate and returns them.
     ///
     /// The result can be empty.
-    pub fn consume_bytes<F>(&mut self, f: F) -> &'a str
+    pub(crate) fn consume_bytes<F>(&mut self, f: F) -> &'a str
     where
         F: Fn(&Stream, u8) -> bool,
     {
@@ -397,7 +467,7 @@ impl<'a> Stream<'a> {
     }
 
     /// Consumes bytes by the predicate.
-    pub fn skip_bytes<F>(&mut self, f: F)
+    pub(crate) fn skip_bytes<F>(&mut self, f: F)
     where
         F: Fn(&Stream, u8) -> bool,
     {
@@ -413,16 +483,25 @@ impl<'a> Stream<'a> {
 
     /// Slices data from `pos` to the current position.
     #[inline]
-    pub fn slice_back(&self, pos: usize) -> &'a str {
+    pub(crate) fn slice_back(&self, pos: usize) -> &'a str {
         &self.text[pos..self.pos]
     }
 
     /// Slices data from the current position to the end.
     #[inline]
-    pub fn slice_tail(&self) -> &'a str {
+    pub(crate) fn slice_tail(&self) -> &'a str {
         &self.text[self.pos..]
     }
 
+    /// Slices data between two byte positions.
+    ///
+    /// Returns `None` when the range is out of bounds,
+    /// reversed or not on a char boundary.
+    #[inline]
+    pub fn slice(&self, start: usize, end: usize) -> Option<&'a str> {
+        self.text.get(start..end)
+    }
+
     /// Parses integer number from the stream.
     ///
     /// Same as [`parse_number()`], but only for integer. Does not refer to any SVG type.
@@ -496,6 +575,34 @@ impl<'a> Stream<'a> {
         Ok(l)
     }
 
+    /// Parses a `true` or `false` keyword.
+    ///
+    /// Leading and trailing spaces are skipped.
+    /// Anything else after the keyword is an error.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidValue` when there is no keyword.
+    /// - `UnexpectedData` when the keyword is followed by other data.
+    pub fn parse_bool(&mut self) -> Result<bool, Error> {
+        self.skip_spaces();
+
+        let value = if self.try_consume_keyword("true") {
+            true
+        } else if self.try_consume_keyword("false") {
+            false
+        } else {
+            return Err(Error::InvalidValue);
+        };
+
+        self.skip_spaces();
+        if !self.at_end() {
+            return Err(Error::UnexpectedData(self.calc_char_pos()));
+        }
+
+        Ok(value)
+    }
+
     /// Skips digits.
     pub fn skip_digits(&mut self) {
         self.skip_bytes(|_, c| c.is_digit());
@@ -509,6 +616,44 @@ impl<'a> Stream<'a> {
     }
 }
 
+/// Matches a keyword against a list of allowed values.
+///
+/// Surrounding spaces are ignored. The comparison is case-sensitive.
+/// Useful for small keyword enumerations like `spreadMethod` or `gradientUnits`.
+///
+/// # Errors
+///
+/// - `InvalidString` listing the allowed values.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::parse_keyword;
+///
+/// let allowed = ["pad", "reflect", "repeat"];
+/// assert_eq!(parse_keyword(" pad ", &allowed).unwrap(), "pad");
+/// assert_eq!(parse_keyword("mirror", &allowed).unwrap_err().to_string(),
+///            "expected 'pad', 'reflect', 'repeat' not 'mirror' at position 1");
+/// ```
+pub fn parse_keyword<'a>(text: &str, allowed: &[&'a str]) -> Result<&'a str, Error> {
+    let mut s = Stream::from(text);
+    s.skip_spaces();
+
+    let start = s.pos();
+    let value = s
+        .slice_tail()
+        .trim_end_matches(|c: char| c.is_ascii() && (c as u8).is_space());
+
+    match allowed.iter().find(|keyword| **keyword == value) {
+        Some(keyword) => Ok(keyword),
+        None => {
+            let mut strings = vec![value.to_string()];
+            strings.extend(allowed.iter().map(|keyword| keyword.to_string()));
+            Err(Error::InvalidString(strings, s.calc_char_pos_at(start)))
+        }
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -520,6 +665,135 @@ mod tests {
         assert_eq!(s.parse_integer().unwrap(), 10);
     }
 
+    #[test]
+    fn is_curr_byte_one_of_1() {
+        let mut s = Stream::from("a, ;");
+        assert!(!s.is_curr_byte_one_of(b", ;"));
+        s.advance(1);
+        assert!(s.is_curr_byte_one_of(b", ;"));
+        s.advance(1);
+        assert!(s.is_curr_byte_one_of(b", ;"));
+        s.advance(1);
+        assert!(s.is_curr_byte_one_of(b", ;"));
+        s.advance(1);
+        assert!(!s.is_curr_byte_one_of(b", ;"));
+    }
+
+    #[test]
+    fn parse_bool_1() {
+        assert!(Stream::from("true").parse_bool().unwrap());
+        assert!(!Stream::from(" false ").parse_bool().unwrap());
+    }
+
+    #[test]
+    fn parse_bool_err_1() {
+        assert_eq!(Stream::from("true 1").parse_bool().unwrap_err().to_string(),
+                   "unexpected data at position 6");
+        assert_eq!(Stream::from("trueish").parse_bool().unwrap_err().to_string(),
+                   "invalid value");
+        assert_eq!(Stream::from("True").parse_bool().unwrap_err().to_string(),
+                   "invalid value");
+        assert_eq!(Stream::from("").parse_bool().unwrap_err().to_string(),
+                   "invalid value");
+    }
+
+    #[test]
+    fn peek_byte_1() {
+        let mut s = Stream::from("ab");
+        assert_eq!(s.peek_byte(0).unwrap(), b'a');
+        assert_eq!(s.peek_byte(1).unwrap(), b'b');
+        assert_eq!(s.peek_byte(2).unwrap_err(), Error::UnexpectedEndOfStream);
+        assert_eq!(s.peek_byte(usize::MAX).unwrap_err(), Error::UnexpectedEndOfStream);
+        s.advance(2);
+        assert_eq!(s.peek_byte(0).unwrap_err(), Error::UnexpectedEndOfStream);
+    }
+
+    #[test]
+    fn remaining_1() {
+        let mut s = Stream::from("abc");
+        assert_eq!(s.remaining(), 3);
+        s.advance(1);
+        assert_eq!(s.remaining(), 2);
+        s.jump_to_end();
+        assert_eq!(s.remaining(), 0);
+    }
+
+    #[test]
+    fn slice_1() {
+        let s = Stream::from("10px цвет");
+        assert_eq!(s.slice(0, 2), Some("10"));
+        assert_eq!(s.slice(5, 7), Some("ц"));
+        assert_eq!(s.slice(5, 6), None);
+        assert_eq!(s.slice(2, 1), None);
+        assert_eq!(s.slice(0, 100), None);
+        assert_eq!(s.slice(13, 13), Some(""));
+    }
+
+    #[test]
+    fn consume_byte_1() {
+        let mut s = Stream::from("é");
+        assert!(s.consume_byte(0xC3).is_err());
+        assert_eq!(s.pos(), 0);
+    }
+
+    #[test]
+    fn parse_keyword_1() {
+        let allowed = ["userSpaceOnUse", "objectBoundingBox"];
+        assert_eq!(parse_keyword("objectBoundingBox", &allowed).unwrap(), "objectBoundingBox");
+        assert_eq!(parse_keyword("\t userSpaceOnUse\n", &allowed).unwrap(), "userSpaceOnUse");
+    }
+
+    #[test]
+    fn parse_keyword_err_1() {
+        let allowed = ["pad", "reflect", "repeat"];
+        assert_eq!(parse_keyword("  Pad", &allowed).unwrap_err(),
+                   Error::InvalidString(vec!["Pad".to_string(), "pad".to_string(),
+                                             "reflect".to_string(), "repeat".to_string()], 3));
+        assert_eq!(parse_keyword("pad repeat", &allowed).unwrap_err().to_string(),
+                   "expected 'pad', 'reflect', 'repeat' not 'pad repeat' at position 1");
+        assert_eq!(parse_keyword("", &allowed).unwrap_err().to_string(),
+                   "expected 'pad', 'reflect', 'repeat' not '' at position 1");
+    }
+
+    #[test]
+    fn try_consume_keyword_1() {
+        let mut s = Stream::from("none");
+        assert!(s.try_consume_keyword("none"));
+        assert!(s.at_end());
+    }
+
+    #[test]
+    fn try_consume_keyword_2() {
+        let mut s = Stream::from("none 1");
+        assert!(s.try_consume_keyword("none"));
+        assert_eq!(s.slice_tail(), " 1");
+    }
+
+    #[test]
+    fn try_consume_keyword_3() {
+        let mut s = Stream::from("nonesuch");
+        assert!(!s.try_consume_keyword("none"));
+        assert_eq!(s.pos(), 0);
+
+        let mut s = Stream::from("none-x");
+        assert!(!s.try_consume_keyword("none"));
+        assert_eq!(s.pos(), 0);
+
+        let mut s = Stream::from("non");
+        assert!(!s.try_consume_keyword("none"));
+    }
+
+    #[test]
+    fn skip_spaces_1() {
+        let mut s = Stream::from(" \u{a0}\u{c}1");
+        s.skip_spaces();
+        assert_eq!(s.pos(), 1);
+
+        s.set_lenient_spaces(true);
+        s.skip_spaces();
+        assert_eq!(s.slice_tail(), "1");
+    }
+
     #[test]
     fn parse_err_integer_1() {
         // error because of overflow
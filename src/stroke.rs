@@ -0,0 +1,144 @@
+use crate::{Error, Length, LengthListParser, Stream};
+
+/// Parses a [`stroke-dasharray`] value.
+///
+/// Returns `None` for the `none` keyword.
+///
+/// # Errors
+///
+/// - `InvalidValue` when any of the lengths is negative.
+/// - `UnexpectedEndOfStream` on an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::{parse_dasharray, Length};
+///
+/// assert_eq!(parse_dasharray("5, 3").unwrap(),
+///            Some(vec![Length::new_number(5.0), Length::new_number(3.0)]));
+/// assert_eq!(parse_dasharray("none").unwrap(), None);
+/// ```
+///
+/// [`stroke-dasharray`]: https://www.w3.org/TR/SVG2/painting.html#StrokeDasharrayProperty
+pub fn parse_dasharray(text: &str) -> Result<Option<Vec<Length>>, Error> {
+    let mut s = Stream::from(text);
+    s.skip_spaces();
+
+    if s.try_consume_keyword("none") {
+        s.skip_spaces();
+        if !s.at_end() {
+            return Err(Error::UnexpectedData(s.calc_char_pos()));
+        }
+
+        return Ok(None);
+    }
+
+    if s.at_end() {
+        return Err(Error::UnexpectedEndOfStream);
+    }
+
+    let mut list = Vec::new();
+    for length in LengthListParser::from(text) {
+        let length = length?;
+        if length.number < 0.0 {
+            return Err(Error::InvalidValue);
+        }
+
+        list.push(length);
+    }
+
+    Ok(Some(list))
+}
+
+/// Parses a [`stroke-miterlimit`] value.
+///
+/// # Errors
+///
+/// - `InvalidValue` when the value is less than 1 or not finite, like `1e999`.
+/// - `InvalidNumber` when the value is not a number.
+///
+/// # Examples
+///
+/// ```
+/// use svgtypes::parse_miterlimit;
+///
+/// assert_eq!(parse_miterlimit("4").unwrap(), 4.0);
+/// assert!(parse_miterlimit("0.5").is_err());
+/// ```
+///
+/// [`stroke-miterlimit`]: https://www.w3.org/TR/SVG2/painting.html#StrokeMiterlimitProperty
+pub fn parse_miterlimit(text: &str) -> Result<f64, Error> {
+    let mut s = Stream::from(text);
+    let n = s.parse_number_allow_overflow()?;
+    s.skip_spaces();
+    if !s.at_end() {
+        return Err(Error::UnexpectedData(s.calc_char_pos()));
+    }
+
+    if !(n.is_finite() && n >= 1.0) {
+        return Err(Error::InvalidValue);
+    }
+
+    Ok(n)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LengthUnit;
+
+    macro_rules! test {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(parse_dasharray($text).unwrap(), $result);
+            }
+        )
+    }
+
+    test!(parse_1, "5,3", Some(vec![Length::new_number(5.0), Length::new_number(3.0)]));
+    test!(parse_2, "none", None);
+    test!(parse_3, "  none ", None);
+    test!(parse_4, " 5px 10% 0 ", Some(vec![
+        Length::new(5.0, LengthUnit::Px),
+        Length::new(10.0, LengthUnit::Percent),
+        Length::new_number(0.0),
+    ]));
+
+    macro_rules! test_err {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(parse_dasharray($text).unwrap_err().to_string(), $result);
+            }
+        )
+    }
+
+    test_err!(parse_err_1, "-5 3", "invalid value");
+    test_err!(parse_err_2, "5 -3", "invalid value");
+    test_err!(parse_err_3, "", "unexpected end of stream");
+    test_err!(parse_err_4, "none 5", "unexpected data at position 6");
+    test_err!(parse_err_5, "5 q", "invalid number at position 3");
+    test_err!(parse_err_6, "nonempty", "invalid number at position 1");
+
+    #[test]
+    fn miterlimit_1() {
+        assert_eq!(parse_miterlimit("4").unwrap(), 4.0);
+        assert_eq!(parse_miterlimit(" 1 ").unwrap(), 1.0);
+        assert_eq!(parse_miterlimit("1e1").unwrap(), 10.0);
+    }
+
+    #[test]
+    fn miterlimit_err_1() {
+        assert_eq!(parse_miterlimit("0.5").unwrap_err(), Error::InvalidValue);
+        assert_eq!(parse_miterlimit("-4").unwrap_err(), Error::InvalidValue);
+        assert_eq!(parse_miterlimit("1e999").unwrap_err(), Error::InvalidValue);
+        assert_eq!(parse_miterlimit("-1e999").unwrap_err(), Error::InvalidValue);
+        assert_eq!(parse_miterlimit("x").unwrap_err(), Error::InvalidNumber(1));
+        assert_eq!(parse_miterlimit("inf").unwrap_err(), Error::InvalidNumber(1));
+        assert_eq!(parse_miterlimit("NaN").unwrap_err(), Error::InvalidNumber(1));
+        assert_eq!(parse_miterlimit("infinity").unwrap_err(), Error::InvalidNumber(1));
+        assert_eq!(parse_miterlimit("4px").unwrap_err(), Error::UnexpectedData(2));
+    }
+}
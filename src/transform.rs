@@ -1,11 +1,12 @@
 use std::f64;
 
-use crate::{Error, Stream};
+use crate::{Error, Length, LengthUnit, Stream, TransformOrigin, ViewBox};
 
 /// Representation of the [`<transform>`] type.
 ///
 /// [`<transform>`]: https://www.w3.org/TR/SVG2/coords.html#InterfaceSVGTransform
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub struct Transform {
     pub a: f64,
@@ -22,6 +23,260 @@ impl Transform {
     pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
         Transform { a, b, c, d, e, f }
     }
+
+    /// Constructs a new transform from an `[a, b, c, d, e, f]` array.
+    #[inline]
+    pub fn from_row(row: [f64; 6]) -> Self {
+        Transform::new(row[0], row[1], row[2], row[3], row[4], row[5])
+    }
+
+    /// Returns the transform as an `[a, b, c, d, e, f]` array.
+    #[inline]
+    pub fn to_row(&self) -> [f64; 6] {
+        [self.a, self.b, self.c, self.d, self.e, self.f]
+    }
+
+    /// Applies the transform to a point.
+    #[inline]
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        let new_x = self.a * x + self.c * y + self.e;
+        let new_y = self.b * x + self.d * y + self.f;
+        (new_x, new_y)
+    }
+
+    /// Returns a copy of `points` with the transform applied.
+    pub fn apply_to_points(&self, points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+        points.iter().map(|&(x, y)| self.apply(x, y)).collect()
+    }
+
+    /// Applies the transform to `points` in place.
+    pub fn transform_points(&self, points: &mut [(f64, f64)]) {
+        for p in points {
+            *p = self.apply(p.0, p.1);
+        }
+    }
+
+    /// Returns an axis-aligned bounding box of a transformed rectangle.
+    pub fn transform_rect(&self, rect: ViewBox) -> ViewBox {
+        let mut points = [
+            (rect.x, rect.y),
+            (rect.x + rect.w, rect.y),
+            (rect.x + rect.w, rect.y + rect.h),
+            (rect.x, rect.y + rect.h),
+        ];
+        self.transform_points(&mut points);
+
+        let mut min_x = f64::MAX;
+        let mut min_y = f64::MAX;
+        let mut max_x = f64::MIN;
+        let mut max_y = f64::MIN;
+        for &(x, y) in &points {
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+
+        ViewBox::new(min_x, min_y, max_x - min_x, max_y - min_y)
+    }
+
+    /// Appends `other` to the current transform.
+    ///
+    /// Computes `self * other`, so `other` is applied to a point first.
+    /// The same as `matrix(self) matrix(other)` in a transform list.
+    #[inline]
+    pub fn append(&mut self, other: &Transform) {
+        *self = multiply(self, other);
+    }
+
+    /// Prepends `other` to the current transform.
+    ///
+    /// Computes `other * self`, so `self` is applied to a point first.
+    /// The same as `matrix(other) matrix(self)` in a transform list.
+    /// Useful for applying a parent transform.
+    #[inline]
+    pub fn prepend(&mut self, other: &Transform) {
+        *self = multiply(other, self);
+    }
+
+    /// Returns a transform applied around the `origin`.
+    ///
+    /// Same as `translate(origin) ts translate(-origin)`, like the CSS `transform-origin` does.
+    ///
+    /// The origin is resolved relative to the `reference` box.
+    /// Percentages are relative to the box size, absolute units use `dpi`
+    /// and `em`/`ex` use `font_size`. The `z_offset` is ignored.
+    pub fn with_origin(
+        ts: &Transform,
+        origin: &TransformOrigin,
+        reference: ViewBox,
+        dpi: f64,
+        font_size: f64,
+    ) -> Transform {
+        let x = reference.x + resolve_length(origin.x_offset, reference.w, dpi, font_size);
+        let y = reference.y + resolve_length(origin.y_offset, reference.h, dpi, font_size);
+
+        let mut new_ts = Transform::new(1.0, 0.0, 0.0, 1.0, x, y);
+        new_ts.append(ts);
+        new_ts.append(&Transform::new(1.0, 0.0, 0.0, 1.0, -x, -y));
+        new_ts
+    }
+
+    /// Returns the transform as an SVG `transform` attribute value.
+    ///
+    /// A pure translation is written as `translate(10 20)` and a pure rotation
+    /// as `rotate(45)`. Everything else is written as `matrix(1 0 0 1 10 20)`.
+    /// Arguments are unitless and separated by spaces.
+    pub fn to_svg_attribute(&self) -> String {
+        match self.simple_form() {
+            SimpleForm::Translate(x, y) => format!("translate({} {})", x, y),
+            SimpleForm::Rotate(angle) => format!("rotate({})", angle),
+            SimpleForm::Matrix => format!(
+                "matrix({} {} {} {} {} {})",
+                self.a, self.b, self.c, self.d, self.e, self.f
+            ),
+        }
+    }
+
+    /// Returns the transform as a CSS `transform` property value.
+    ///
+    /// A pure translation is written as `translate(10px, 20px)` and a pure rotation
+    /// as `rotate(45deg)`. Everything else is written as `matrix(1, 0, 0, 1, 10, 20)`,
+    /// since CSS `matrix()` takes unitless numbers as well.
+    /// Arguments are separated by commas, as CSS requires.
+    pub fn to_css_property(&self) -> String {
+        match self.simple_form() {
+            SimpleForm::Translate(x, y) => format!("translate({}px, {}px)", x, y),
+            SimpleForm::Rotate(angle) => format!("rotate({}deg)", angle),
+            SimpleForm::Matrix => format!(
+                "matrix({}, {}, {}, {}, {}, {})",
+                self.a, self.b, self.c, self.d, self.e, self.f
+            ),
+        }
+    }
+
+    fn simple_form(&self) -> SimpleForm {
+        if self.a == 1.0 && self.b == 0.0 && self.c == 0.0 && self.d == 1.0 {
+            SimpleForm::Translate(self.e, self.f)
+        } else if self.e == 0.0
+            && self.f == 0.0
+            && self.a == self.d
+            && self.b == -self.c
+            && (self.a * self.a + self.b * self.b - 1.0).abs() <= f64::EPSILON
+        {
+            SimpleForm::Rotate(self.get_rotate())
+        } else {
+            SimpleForm::Matrix
+        }
+    }
+
+    /// Returns the transform determinant, i.e. `a * d - b * c`.
+    #[inline]
+    pub fn determinant(&self) -> f64 {
+        self.a * self.d - self.b * self.c
+    }
+
+    /// Returns an inverted transform.
+    ///
+    /// Returns `None` when the transform is not invertible, i.e. its determinant
+    /// is zero or not finite, or when the inverse would overflow.
+    pub fn invert(&self) -> Option<Transform> {
+        let det = self.determinant();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        // A subnormal determinant still overflows here.
+        let inv_det = 1.0 / det;
+        if !inv_det.is_finite() {
+            return None;
+        }
+
+        Some(Transform {
+            a: self.d * inv_det,
+            b: -self.b * inv_det,
+            c: -self.c * inv_det,
+            d: self.a * inv_det,
+            e: (self.c * self.f - self.d * self.e) * inv_det,
+            f: (self.b * self.e - self.a * self.f) * inv_det,
+        })
+    }
+
+    /// Returns the rotation angle in degrees, in the `(-180, 180]` range.
+    ///
+    /// The angle is taken from the first matrix column, so it stays correct
+    /// for scaled and skewed transforms and doesn't divide by zero when `a` is zero.
+    /// Same as `rotation` from [`decompose()`](#method.decompose).
+    #[inline]
+    pub fn get_rotate(&self) -> f64 {
+        let angle = self.b.atan2(self.a).to_degrees();
+        // `atan2(-0.0, -1.0)` is `-180`, which is outside of the documented range.
+        if angle == -180.0 {
+            180.0
+        } else {
+            angle
+        }
+    }
+
+    /// Decomposes the transform into separate components.
+    ///
+    /// The result is equivalent to
+    /// `translate(tx ty) rotate(rotation) skewX(skew_x) scale(scale_x scale_y)`.
+    ///
+    /// `scale_x` is always non-negative. A reflection is stored as a negative `scale_y`.
+    /// Angles are in degrees, and positive values rotate from the X axis towards the Y axis,
+    /// just like in the SVG `rotate()` function.
+    ///
+    /// A transform with a zero `scale_x` cannot be restored from its components.
+    pub fn decompose(&self) -> DecomposedTransform {
+        let scale_x = (self.a * self.a + self.b * self.b).sqrt();
+
+        let det = self.determinant();
+        let (scale_y, skew_x) = if scale_x != 0.0 {
+            let scale_y = det / scale_x;
+            let skew_x = if det != 0.0 {
+                ((self.a * self.c + self.b * self.d) / det).atan()
+            } else {
+                0.0
+            };
+
+            (scale_y, skew_x)
+        } else {
+            ((self.c * self.c + self.d * self.d).sqrt(), 0.0)
+        };
+
+        DecomposedTransform {
+            tx: self.e,
+            ty: self.f,
+            rotation: self.get_rotate(),
+            scale_x,
+            scale_y,
+            skew_x: skew_x.to_degrees(),
+        }
+    }
+}
+
+/// A function that can represent a transform exactly.
+enum SimpleForm {
+    Translate(f64, f64),
+    Rotate(f64),
+    Matrix,
+}
+
+/// Transform components.
+///
+/// See [`Transform::decompose`] for details.
+///
+/// [`Transform::decompose`]: struct.Transform.html#method.decompose
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub struct DecomposedTransform {
+    pub tx: f64,
+    pub ty: f64,
+    pub rotation: f64,
+    pub scale_x: f64,
+    pub scale_y: f64,
+    pub skew_x: f64,
 }
 
 impl Default for Transform {
@@ -54,6 +309,16 @@ pub enum TransformListToken {
     Rotate {
         angle: f64,
     },
+    /// `rotate(<rotate-angle> <cx> <cy>)`.
+    ///
+    /// Emitted only when [`TransformListParser::keep_rotate_center`] is enabled.
+    ///
+    /// [`TransformListParser::keep_rotate_center`]: struct.TransformListParser.html#method.keep_rotate_center
+    RotateAt {
+        angle: f64,
+        cx: f64,
+        cy: f64,
+    },
     SkewX {
         angle: f64,
     },
@@ -62,6 +327,76 @@ pub enum TransformListToken {
     },
 }
 
+impl std::fmt::Display for TransformListToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            TransformListToken::Matrix {
+                a,
+                b,
+                c,
+                d,
+                e,
+                f: f_,
+            } => {
+                write!(f, "matrix({} {} {} {} {} {})", a, b, c, d, e, f_)
+            }
+            TransformListToken::Translate { tx, ty } => {
+                if ty == 0.0 {
+                    write!(f, "translate({})", tx)
+                } else {
+                    write!(f, "translate({} {})", tx, ty)
+                }
+            }
+            TransformListToken::Scale { sx, sy } => {
+                if sx == sy {
+                    write!(f, "scale({})", sx)
+                } else {
+                    write!(f, "scale({} {})", sx, sy)
+                }
+            }
+            TransformListToken::Rotate { angle } => write!(f, "rotate({})", angle),
+            TransformListToken::RotateAt { angle, cx, cy } => {
+                write!(f, "rotate({} {} {})", angle, cx, cy)
+            }
+            TransformListToken::SkewX { angle } => write!(f, "skewX({})", angle),
+            TransformListToken::SkewY { angle } => write!(f, "skewY({})", angle),
+        }
+    }
+}
+
+/// A list of transform functions.
+///
+/// Unlike [`Transform`], which merges everything into a single matrix,
+/// keeps each function separately, so it can be written back as authored.
+/// `rotate(<rotate-angle> <cx> <cy>)` is preserved as `RotateAt`.
+///
+/// [`Transform`]: struct.Transform.html
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct TransformList(pub Vec<TransformListToken>);
+
+impl std::str::FromStr for TransformList {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Error> {
+        let tokens = TransformListParser::from(text).keep_rotate_center(true);
+        Ok(TransformList(tokens.collect::<Result<_, _>>()?))
+    }
+}
+
+impl std::fmt::Display for TransformList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for (i, token) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, " ")?;
+            }
+
+            write!(f, "{}", token)?;
+        }
+
+        Ok(())
+    }
+}
+
 /// A pull-based [`<transform-list>`] parser.
 ///
 /// # Errors
@@ -74,6 +409,7 @@ pub enum TransformListToken {
 ///   It will be automatically split into three `Transform` tokens:
 ///   `translate(<cx> <cy>) rotate(<rotate-angle>) translate(-<cx> -<cy>)`.
 ///   Just like the spec is stated.
+///   Use [`keep_rotate_center()`] to get a single `RotateAt` token instead.
 ///
 /// # Examples
 ///
@@ -87,11 +423,13 @@ pub enum TransformListToken {
 /// ```
 ///
 /// [`<transform-list>`]: https://www.w3.org/TR/SVG11/shapes.html#PointsBNF
+/// [`keep_rotate_center()`]: #method.keep_rotate_center
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct TransformListParser<'a> {
     stream: Stream<'a>,
     rotate_ts: Option<(f64, f64)>,
     last_angle: Option<f64>,
+    keep_rotate_center: bool,
 }
 
 impl<'a> From<&'a str> for TransformListParser<'a> {
@@ -100,10 +438,23 @@ impl<'a> From<&'a str> for TransformListParser<'a> {
             stream: Stream::from(text),
             rotate_ts: None,
             last_angle: None,
+            keep_rotate_center: false,
         }
     }
 }
 
+impl<'a> TransformListParser<'a> {
+    /// Sets whether `rotate(<rotate-angle> <cx> <cy>)` should be returned as a single
+    /// `RotateAt` token instead of being split into three tokens.
+    ///
+    /// Disabled by default.
+    #[inline]
+    pub fn keep_rotate_center(mut self, keep: bool) -> Self {
+        self.keep_rotate_center = keep;
+        self
+    }
+}
+
 impl<'a> Iterator for TransformListParser<'a> {
     type Item = Result<TransformListToken, Error>;
 
@@ -189,10 +540,15 @@ impl<'a> TransformListParser<'a> {
                     // translate(<cx>, <cy>) rotate(<rotate-angle>) translate(-<cx>, -<cy>).'
                     let cx = s.parse_list_number()?;
                     let cy = s.parse_list_number()?;
-                    self.rotate_ts = Some((cx, cy));
-                    self.last_angle = Some(a);
 
-                    TransformListToken::Translate { tx: cx, ty: cy }
+                    if self.keep_rotate_center {
+                        TransformListToken::RotateAt { angle: a, cx, cy }
+                    } else {
+                        self.rotate_ts = Some((cx, cy));
+                        self.last_angle = Some(a);
+
+                        TransformListToken::Translate { tx: cx, ty: cy }
+                    }
                 } else {
                     TransformListToken::Rotate { angle: a }
                 }
@@ -224,41 +580,97 @@ impl std::str::FromStr for Transform {
     type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Error> {
-        let tokens = TransformListParser::from(text);
-        let mut ts = Transform::default();
+        parse_transform(text, false)
+    }
+}
 
-        for token in tokens {
-            match token? {
-                TransformListToken::Matrix { a, b, c, d, e, f } => {
-                    ts = multiply(&ts, &Transform::new(a, b, c, d, e, f))
-                }
-                TransformListToken::Translate { tx, ty } => {
-                    ts = multiply(&ts, &Transform::new(1.0, 0.0, 0.0, 1.0, tx, ty))
-                }
-                TransformListToken::Scale { sx, sy } => {
-                    ts = multiply(&ts, &Transform::new(sx, 0.0, 0.0, sy, 0.0, 0.0))
-                }
-                TransformListToken::Rotate { angle } => {
-                    let v = angle.to_radians();
-                    let a = v.cos();
-                    let b = v.sin();
-                    let c = -b;
-                    let d = a;
-                    ts = multiply(&ts, &Transform::new(a, b, c, d, 0.0, 0.0))
-                }
-                TransformListToken::SkewX { angle } => {
-                    let c = angle.to_radians().tan();
-                    ts = multiply(&ts, &Transform::new(1.0, 0.0, c, 1.0, 0.0, 0.0))
-                }
-                TransformListToken::SkewY { angle } => {
-                    let b = angle.to_radians().tan();
-                    ts = multiply(&ts, &Transform::new(1.0, b, 0.0, 1.0, 0.0, 0.0))
-                }
+impl Transform {
+    /// Parses a `Transform` from a string and checks that it didn't overflow.
+    ///
+    /// Same as `FromStr`, but instead of silently producing infinities,
+    /// like for `scale(1e200) scale(1e200)`, an error will be returned.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidValue` when the accumulated matrix is not finite.
+    /// - Any error that `FromStr` can return.
+    pub fn from_str_checked(text: &str) -> Result<Self, Error> {
+        parse_transform(text, true)
+    }
+}
+
+fn parse_transform(text: &str, checked: bool) -> Result<Transform, Error> {
+    let tokens = TransformListParser::from(text);
+    let mut ts = Transform::default();
+
+    for token in tokens {
+        match token? {
+            TransformListToken::Matrix { a, b, c, d, e, f } => {
+                ts = multiply(&ts, &Transform::new(a, b, c, d, e, f))
+            }
+            TransformListToken::Translate { tx, ty } => {
+                ts = multiply(&ts, &Transform::new(1.0, 0.0, 0.0, 1.0, tx, ty))
+            }
+            TransformListToken::Scale { sx, sy } => {
+                ts = multiply(&ts, &Transform::new(sx, 0.0, 0.0, sy, 0.0, 0.0))
+            }
+            TransformListToken::Rotate { angle } => ts = multiply(&ts, &rotate(angle)),
+            TransformListToken::RotateAt { angle, cx, cy } => {
+                ts = multiply(&ts, &Transform::new(1.0, 0.0, 0.0, 1.0, cx, cy));
+                ts = multiply(&ts, &rotate(angle));
+                ts = multiply(&ts, &Transform::new(1.0, 0.0, 0.0, 1.0, -cx, -cy));
+            }
+            TransformListToken::SkewX { angle } => {
+                let c = angle.to_radians().tan();
+                ts = multiply(&ts, &Transform::new(1.0, 0.0, c, 1.0, 0.0, 0.0))
+            }
+            TransformListToken::SkewY { angle } => {
+                let b = angle.to_radians().tan();
+                ts = multiply(&ts, &Transform::new(1.0, b, 0.0, 1.0, 0.0, 0.0))
             }
         }
 
-        Ok(ts)
+        if checked && !is_finite(&ts) {
+            return Err(Error::InvalidValue);
+        }
     }
+
+    Ok(ts)
+}
+
+fn resolve_length(length: Length, base: f64, dpi: f64, font_size: f64) -> f64 {
+    let n = length.number;
+    match length.unit {
+        LengthUnit::None | LengthUnit::Px => n,
+        LengthUnit::Em => n * font_size,
+        LengthUnit::Ex => n * font_size / 2.0,
+        LengthUnit::In => n * dpi,
+        LengthUnit::Cm => n * dpi / 2.54,
+        LengthUnit::Mm => n * dpi / 25.4,
+        LengthUnit::Pt => n * dpi / 72.0,
+        LengthUnit::Pc => n * dpi / 6.0,
+        LengthUnit::Percent => n * base / 100.0,
+    }
+}
+
+#[inline]
+fn rotate(angle: f64) -> Transform {
+    let v = angle.to_radians();
+    let a = v.cos();
+    let b = v.sin();
+    let c = -b;
+    let d = a;
+    Transform::new(a, b, c, d, 0.0, 0.0)
+}
+
+#[inline]
+fn is_finite(ts: &Transform) -> bool {
+    ts.a.is_finite()
+        && ts.b.is_finite()
+        && ts.c.is_finite()
+        && ts.d.is_finite()
+        && ts.e.is_finite()
+        && ts.f.is_finite()
 }
 
 #[inline(never)]
@@ -335,6 +747,83 @@ mod tests {
         "matrix(1 0 0.9999999999999999 1 0 0)"
     );
 
+    test!(parse_whitespace_1,
+        "matrix(1\t0\n0 1 0 0)",
+        "matrix(1 0 0 1 0 0)"
+    );
+
+    test!(parse_whitespace_2,
+        "\n\ttranslate(\n  10\t\t20\r\n)\n\tscale( 2 \t 3 )\n",
+        "matrix(2 0 0 3 10 20)"
+    );
+
+    test!(parse_whitespace_3,
+        "rotate(\t30\n10\r\n20\t)",
+        "matrix(0.8660254037844387 0.49999999999999994 -0.49999999999999994 0.8660254037844387 11.339745962155611 -2.3205080756887746)"
+    );
+
+    test!(parse_whitespace_4,
+        "skewX(\n45\n)\tskewY(\t0\t)",
+        "matrix(1 0 0.9999999999999999 1 0 0)"
+    );
+
+    test!(parse_whitespace_5,
+        "translate(10)\n,\nscale(2)",
+        "matrix(2 0 0 2 10 0)"
+    );
+
+    test!(parse_whitespace_6,
+        "translate  \n(10 20)",
+        "matrix(1 0 0 1 10 20)"
+    );
+
+    #[test]
+    fn rotate_at_1() {
+        let mut p = TransformListParser::from("rotate(30 10 20)");
+        assert_eq!(p.next().unwrap().unwrap(), TransformListToken::Translate { tx: 10.0, ty: 20.0 });
+        assert_eq!(p.next().unwrap().unwrap(), TransformListToken::Rotate { angle: 30.0 });
+        assert_eq!(p.next().unwrap().unwrap(), TransformListToken::Translate { tx: -10.0, ty: -20.0 });
+        assert!(p.next().is_none());
+    }
+
+    #[test]
+    fn rotate_at_2() {
+        let mut p = TransformListParser::from("rotate(30 10 20) rotate(45)").keep_rotate_center(true);
+        assert_eq!(p.next().unwrap().unwrap(), TransformListToken::RotateAt { angle: 30.0, cx: 10.0, cy: 20.0 });
+        assert_eq!(p.next().unwrap().unwrap(), TransformListToken::Rotate { angle: 45.0 });
+        assert!(p.next().is_none());
+    }
+
+    macro_rules! test_list {
+        ($name:ident, $text:expr, $result:expr) => (
+            #[test]
+            fn $name() {
+                assert_eq!(TransformList::from_str($text).unwrap().to_string(), $result);
+            }
+        )
+    }
+
+    test_list!(list_1, "scale(2) translate(10,-20)", "scale(2) translate(10 -20)");
+    test_list!(list_2, "rotate(30 10 20) skewX(15) skewY(-5)", "rotate(30 10 20) skewX(15) skewY(-5)");
+    test_list!(list_3, "matrix(1,0,0,1,10,20) scale(2 3) translate(5)",
+                       "matrix(1 0 0 1 10 20) scale(2 3) translate(5)");
+    test_list!(list_4, "  ", "");
+
+    #[test]
+    fn list_5() {
+        let list = TransformList::from_str("scale(2) translate(10,-20)").unwrap();
+        assert_eq!(list.0, vec![
+            TransformListToken::Scale { sx: 2.0, sy: 2.0 },
+            TransformListToken::Translate { tx: 10.0, ty: -20.0 },
+        ]);
+    }
+
+    #[test]
+    fn list_err_1() {
+        assert_eq!(TransformList::from_str("scale(2) rect()").unwrap_err().to_string(),
+                   "unexpected data at position 10");
+    }
+
     macro_rules! test_err {
         ($name:ident, $text:expr, $result:expr) => (
             #[test]
@@ -345,6 +834,155 @@ mod tests {
         )
     }
 
+    fn is_fuzzy_eq(ts1: &Transform, ts2: &Transform) -> bool {
+        let eq = |a: f64, b: f64| (a - b).abs() < 1e-9;
+        eq(ts1.a, ts2.a) && eq(ts1.b, ts2.b) && eq(ts1.c, ts2.c)
+            && eq(ts1.d, ts2.d) && eq(ts1.e, ts2.e) && eq(ts1.f, ts2.f)
+    }
+
+    #[test]
+    fn append_1() {
+        let mut ts = Transform::from_str("translate(10 20)").unwrap();
+        ts.append(&Transform::from_str("scale(2)").unwrap());
+        assert_eq!(ts, Transform::from_str("translate(10 20) scale(2)").unwrap());
+    }
+
+    #[test]
+    fn to_row_1() {
+        let ts = Transform::from_str("translate(10 20)").unwrap();
+        assert_eq!(ts.to_row(), [1.0, 0.0, 0.0, 1.0, 10.0, 20.0]);
+    }
+
+    #[test]
+    fn from_row_1() {
+        let row = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert_eq!(Transform::from_row(row), Transform::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0));
+        assert_eq!(Transform::from_row(row).to_row(), row);
+    }
+
+    #[test]
+    fn prepend_1() {
+        let mut ts = Transform::from_str("scale(2)").unwrap();
+        ts.prepend(&Transform::from_str("translate(10 20)").unwrap());
+        assert_eq!(ts, Transform::from_str("translate(10 20) scale(2)").unwrap());
+        assert_eq!(ts.apply(1.0, 1.0), (12.0, 22.0));
+
+        let mut ts = Transform::from_str("scale(2)").unwrap();
+        ts.append(&Transform::from_str("translate(10 20)").unwrap());
+        assert_eq!(ts, Transform::from_str("scale(2) translate(10 20)").unwrap());
+        assert_eq!(ts.apply(1.0, 1.0), (22.0, 42.0));
+    }
+
+    #[test]
+    fn determinant_1() {
+        let ts = Transform::from_str("rotate(30)").unwrap();
+        assert!((ts.determinant() - 1.0).abs() < 1e-9);
+
+        let ts = Transform::from_str("scale(2, 3)").unwrap();
+        assert_eq!(ts.determinant(), 6.0);
+
+        let ts = Transform::from_str("scale(-1, 1)").unwrap();
+        assert_eq!(ts.determinant(), -1.0);
+    }
+
+    fn recompose(d: &DecomposedTransform) -> Transform {
+        let text = format!("translate({} {}) rotate({}) skewX({}) scale({} {})",
+                           d.tx, d.ty, d.rotation, d.skew_x, d.scale_x, d.scale_y);
+        Transform::from_str(&text).unwrap()
+    }
+
+    #[test]
+    fn decompose_1() {
+        let ts = Transform::from_str("translate(10 20) rotate(30) skewX(15) scale(2 3)").unwrap();
+        let d = ts.decompose();
+        assert!((d.tx - 10.0).abs() < 1e-9);
+        assert!((d.ty - 20.0).abs() < 1e-9);
+        assert!((d.rotation - 30.0).abs() < 1e-9);
+        assert!((d.skew_x - 15.0).abs() < 1e-9);
+        assert!((d.scale_x - 2.0).abs() < 1e-9);
+        assert!((d.scale_y - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decompose_2() {
+        let ts = Transform::new(0.5, -1.2, 3.4, 0.7, -5.0, 6.0);
+        assert!(is_fuzzy_eq(&recompose(&ts.decompose()), &ts));
+    }
+
+    #[test]
+    fn decompose_3() {
+        let ts = Transform::from_str("scale(-1 1) rotate(45)").unwrap();
+        let d = ts.decompose();
+        assert!(d.scale_x > 0.0);
+        assert!(d.scale_y < 0.0);
+        assert!(is_fuzzy_eq(&recompose(&d), &ts));
+    }
+
+    #[test]
+    fn decompose_4() {
+        let d = Transform::default().decompose();
+        assert_eq!(d, DecomposedTransform {
+            tx: 0.0, ty: 0.0, rotation: 0.0, scale_x: 1.0, scale_y: 1.0, skew_x: 0.0
+        });
+    }
+
+    #[test]
+    fn get_rotate_1() {
+        let angle = |text: &str| Transform::from_str(text).unwrap().get_rotate();
+        assert!((angle("rotate(0) scale(2, 1)") - 0.0).abs() < 1e-9);
+        assert!((angle("rotate(90) scale(2, 1)") - 90.0).abs() < 1e-9);
+        assert!((angle("rotate(180) scale(2, 1)") - 180.0).abs() < 1e-9);
+        assert!((angle("rotate(270) scale(2, 1)") - -90.0).abs() < 1e-9);
+        assert!((angle("rotate(-45) scale(2, 1)") - -45.0).abs() < 1e-9);
+        assert!((angle("rotate(30) skewX(20) scale(3 0.5)") - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn get_rotate_2() {
+        assert_eq!(Transform::new(0.0, 1.0, -1.0, 0.0, 0.0, 0.0).get_rotate(), 90.0);
+        assert_eq!(Transform::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0).get_rotate(), 0.0);
+        assert_eq!(Transform::new(-1.0, 0.0, 0.0, -1.0, 0.0, 0.0).get_rotate(), 180.0);
+        assert_eq!(Transform::new(-1.0, -0.0, 0.0, -1.0, 0.0, 0.0).get_rotate(), 180.0);
+    }
+
+    #[test]
+    fn invert_1() {
+        let ts = Transform::from_str("translate(10 20) scale(2) rotate(30)").unwrap();
+        let mut inv = ts.invert().unwrap();
+        inv.append(&ts);
+        assert!(is_fuzzy_eq(&inv, &Transform::default()));
+
+        let mut ts2 = ts;
+        ts2.append(&ts.invert().unwrap());
+        assert!(is_fuzzy_eq(&ts2, &Transform::default()));
+    }
+
+    #[test]
+    fn invert_2() {
+        let ts = Transform::from_str("translate(10 20)").unwrap();
+        assert_eq!(ts.invert().unwrap(), Transform::new(1.0, 0.0, 0.0, 1.0, -10.0, -20.0));
+    }
+
+    #[test]
+    fn invert_3() {
+        // Small, but still invertible.
+        let ts = Transform::from_str("scale(1e-9)").unwrap();
+        let inv = ts.invert().unwrap();
+        assert!((inv.a - 1e9).abs() < 1e-3);
+        assert!((inv.d - 1e9).abs() < 1e-3);
+
+        let mut ts2 = ts;
+        ts2.append(&inv);
+        assert!(is_fuzzy_eq(&ts2, &Transform::default()));
+    }
+
+    #[test]
+    fn invert_degenerate_1() {
+        assert!(Transform::from_str("scale(0 1)").unwrap().invert().is_none());
+        assert!(Transform::new(1.0, 2.0, 2.0, 4.0, 5.0, 6.0).invert().is_none());
+        assert!(Transform::new(1e-200, 0.0, 0.0, 1e-200, 0.0, 0.0).invert().is_none());
+    }
+
     test_err!(parse_err_1, "text", "unexpected end of stream");
 
     #[test]
@@ -360,16 +998,162 @@ mod tests {
     #[test]
     fn parse_err_4() {
         let mut ts = TransformListParser::from(" ");
-        assert_eq!(ts.next().is_none(), true);
+        assert!(ts.next().is_none());
     }
 
     #[test]
     fn parse_err_5() {
         let mut ts = TransformListParser::from("\x01");
-        assert_eq!(ts.next().unwrap().is_err(), true);
+        assert!(ts.next().unwrap().is_err());
     }
 
     test_err!(parse_err_6, "rect()", "unexpected data at position 1");
 
     test_err!(parse_err_7, "scale(2) rect()", "unexpected data at position 10");
+
+    #[test]
+    fn apply_1() {
+        let ts = Transform::from_str("translate(10 20) scale(2)").unwrap();
+        assert_eq!(ts.apply(1.0, 2.0), (12.0, 24.0));
+    }
+
+    #[test]
+    fn apply_to_points_1() {
+        let ts = Transform::from_str("translate(10 20)").unwrap();
+        let points = vec![(0.0, 0.0), (1.0, 2.0)];
+        assert_eq!(ts.apply_to_points(&points), vec![(10.0, 20.0), (11.0, 22.0)]);
+        assert_eq!(points, vec![(0.0, 0.0), (1.0, 2.0)]);
+    }
+
+    #[test]
+    fn transform_points_1() {
+        let ts = Transform::from_str("rotate(90)").unwrap();
+        let mut points = [(1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        ts.transform_points(&mut points);
+
+        let expected = [(0.0, 1.0), (-1.0, 1.0), (-1.0, 0.0)];
+        for (p1, p2) in points.iter().zip(expected.iter()) {
+            assert!((p1.0 - p2.0).abs() < 1e-9 && (p1.1 - p2.1).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn transform_rect_1() {
+        let ts = Transform::from_str("rotate(90)").unwrap();
+        let r = ts.transform_rect(ViewBox::new(10.0, 20.0, 30.0, 40.0));
+        assert!((r.x - -60.0).abs() < 1e-9);
+        assert!((r.y - 10.0).abs() < 1e-9);
+        assert!((r.w - 40.0).abs() < 1e-9);
+        assert!((r.h - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn transform_rect_2() {
+        let ts = Transform::from_str("translate(5 5) scale(2)").unwrap();
+        let r = ts.transform_rect(ViewBox::new(0.0, 0.0, 10.0, 20.0));
+        assert_eq!(r, ViewBox::new(5.0, 5.0, 20.0, 40.0));
+    }
+
+    #[test]
+    fn with_origin_1() {
+        let ts = Transform::from_str("rotate(90)").unwrap();
+        let origin = TransformOrigin::from_str("left top").unwrap();
+        let vb = ViewBox::new(10.0, 20.0, 100.0, 50.0);
+        let ts = Transform::with_origin(&ts, &origin, vb, 96.0, 12.0);
+
+        let (x, y) = ts.apply(10.0, 20.0);
+        assert!((x - 10.0).abs() < 1e-9 && (y - 20.0).abs() < 1e-9);
+
+        let (x, y) = ts.apply(20.0, 20.0);
+        assert!((x - 10.0).abs() < 1e-9 && (y - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn with_origin_2() {
+        let ts = Transform::from_str("scale(2)").unwrap();
+        let origin = TransformOrigin::from_str("center").unwrap();
+        let vb = ViewBox::new(0.0, 0.0, 100.0, 50.0);
+        let ts = Transform::with_origin(&ts, &origin, vb, 96.0, 12.0);
+        assert_eq!(ts, Transform::new(2.0, 0.0, 0.0, 2.0, -50.0, -25.0));
+    }
+
+    #[test]
+    fn with_origin_3() {
+        let ts = Transform::from_str("scale(2)").unwrap();
+        let origin = TransformOrigin::from_str("1in 2em").unwrap();
+        let vb = ViewBox::new(0.0, 0.0, 100.0, 50.0);
+        let ts = Transform::with_origin(&ts, &origin, vb, 96.0, 12.0);
+        assert_eq!(ts, Transform::new(2.0, 0.0, 0.0, 2.0, -96.0, -24.0));
+    }
+
+    #[test]
+    fn to_svg_attribute_1() {
+        let ts = Transform::from_str("translate(10 20) scale(2)").unwrap();
+        assert_eq!(ts.to_svg_attribute(), "matrix(2 0 0 2 10 20)");
+        assert_eq!(Transform::from_str(&ts.to_svg_attribute()).unwrap(), ts);
+    }
+
+    #[test]
+    fn to_svg_attribute_2() {
+        let ts = Transform::from_str("translate(10 -20.5)").unwrap();
+        assert_eq!(ts.to_svg_attribute(), "translate(10 -20.5)");
+        assert_eq!(Transform::from_str(&ts.to_svg_attribute()).unwrap(), ts);
+    }
+
+    #[test]
+    fn to_svg_attribute_3() {
+        let ts = Transform::new(0.0, 1.0, -1.0, 0.0, 0.0, 0.0);
+        assert_eq!(ts.to_svg_attribute(), "rotate(90)");
+    }
+
+    #[test]
+    fn to_css_property_1() {
+        let ts = Transform::from_str("translate(10 20) scale(2)").unwrap();
+        assert_eq!(ts.to_css_property(), "matrix(2, 0, 0, 2, 10, 20)");
+        assert_eq!(Transform::from_str(&ts.to_css_property()).unwrap(), ts);
+    }
+
+    #[test]
+    fn to_css_property_2() {
+        let ts = Transform::from_str("translate(10 -20.5)").unwrap();
+        assert_eq!(ts.to_css_property(), "translate(10px, -20.5px)");
+        assert_eq!(Transform::default().to_css_property(), "translate(0px, 0px)");
+    }
+
+    #[test]
+    fn to_css_property_3() {
+        let ts = Transform::new(0.0, 1.0, -1.0, 0.0, 0.0, 0.0);
+        assert_eq!(ts.to_css_property(), "rotate(90deg)");
+    }
+
+    #[test]
+    fn to_css_property_4() {
+        // A rotation around a point has a translation part.
+        let ts = Transform::from_str("rotate(90 10 10)").unwrap();
+        assert!(ts.to_css_property().starts_with("matrix("));
+    }
+
+    #[test]
+    fn parse_checked_1() {
+        let ts = Transform::from_str_checked("scale(2) translate(10 20)").unwrap();
+        assert_eq!(ts, Transform::new(2.0, 0.0, 0.0, 2.0, 20.0, 40.0));
+    }
+
+    #[test]
+    fn parse_checked_err_1() {
+        // Unchecked parsing silently overflows.
+        let ts = Transform::from_str("scale(1e200) scale(1e200)").unwrap();
+        assert!(ts.a.is_infinite());
+
+        assert_eq!(Transform::from_str_checked("scale(1e200) scale(1e200)").unwrap_err(),
+                   Error::InvalidValue);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let ts = Transform::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+        let json = serde_json::to_string(&ts).unwrap();
+        assert_eq!(serde_json::from_str::<Transform>(&json).unwrap(), ts);
+    }
 }
@@ -92,11 +92,25 @@ impl std::error::Error for TransformOriginError {
     }
 }
 
+impl std::fmt::Display for TransformOrigin {
+    /// Writes offsets as lengths. The z offset is written only when it is not zero.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {}", self.x_offset, self.y_offset)?;
+
+        if !self.z_offset.is_zero() {
+            write!(f, " {}", self.z_offset)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl std::str::FromStr for TransformOrigin {
     type Err = TransformOriginError;
 
     fn from_str(text: &str) -> Result<Self, TransformOriginError> {
         let mut stream = Stream::from(text);
+        stream.skip_spaces();
 
         if stream.at_end() {
             return Err(TransformOriginError::MissingParameters);
@@ -104,28 +118,27 @@ impl std::str::FromStr for TransformOrigin {
 
         let parse_part = |stream: &mut Stream| {
             if let Ok(dp) = stream.parse_directional_position() {
-                Some(Position::DirectionalPosition(dp))
+                Ok(Position::DirectionalPosition(dp))
             } else if let Ok(l) = stream.parse_length() {
-                Some(Position::Length(l))
+                Ok(Position::Length(l))
             } else {
-                None
+                Err(TransformOriginError::InvalidParameters)
             }
         };
 
-        let first_arg = parse_part(&mut stream);
+        let first_arg = parse_part(&mut stream)?;
         let mut second_arg = None;
         let mut third_arg = None;
 
+        stream.skip_spaces();
+        stream.parse_list_separator();
         if !stream.at_end() {
-            stream.skip_spaces();
-            stream.parse_list_separator();
-            second_arg =
-                Some(parse_part(&mut stream).ok_or(TransformOriginError::InvalidParameters)?);
+            second_arg = Some(parse_part(&mut stream)?);
         }
 
+        stream.skip_spaces();
+        stream.parse_list_separator();
         if !stream.at_end() {
-            stream.skip_spaces();
-            stream.parse_list_separator();
             third_arg = Some(
                 stream
                     .parse_length()
@@ -139,8 +152,12 @@ impl std::str::FromStr for TransformOrigin {
             return Err(TransformOriginError::InvalidParameters);
         }
 
-        let result = match (first_arg, second_arg, third_arg) {
-            (Some(p), None, None) => {
+        let result = match (first_arg, second_arg) {
+            (p, None) => {
+                // 'If only one value is specified, the second value is assumed to be center.'
+                //
+                // Only `top` and `bottom` are not horizontal, so they set the Y offset.
+                // Lengths, `left`, `right` and `center` set the X offset.
                 let (x_offset, y_offset) = if p.is_horizontal() {
                     (p.into(), DirectionalPosition::Center.into())
                 } else {
@@ -149,36 +166,29 @@ impl std::str::FromStr for TransformOrigin {
 
                 TransformOrigin::new(x_offset, y_offset, Length::new(0.0, LengthUnit::Px))
             }
-            (Some(p1), Some(p2), length) => {
-                if let Some(length) = length {
+            (p1, Some(p2)) => {
+                if let Some(length) = third_arg {
                     if length.unit == LengthUnit::Percent {
                         return Err(TransformOriginError::ZIndexIsPercentage);
                     }
                 }
 
-                let length = length.unwrap_or(Length::new(0.0, LengthUnit::Px));
+                let length = third_arg.unwrap_or(Length::new(0.0, LengthUnit::Px));
 
-                let check = |pos| match pos {
-                    Position::Length(_) => true,
-                    Position::DirectionalPosition(dp) => dp == DirectionalPosition::Center,
-                };
-
-                let only_keyword_is_center = check(p1) && check(p2);
+                let is_keyword = |pos| matches!(pos, Position::DirectionalPosition(_));
 
-                if only_keyword_is_center {
+                if p1.is_horizontal() && p2.is_vertical() {
                     TransformOrigin::new(p1.into(), p2.into(), length)
+                } else if is_keyword(p1) && is_keyword(p2) && p1.is_vertical() && p2.is_horizontal()
+                {
+                    // Two keywords can be in any order, like `top left`.
+                    TransformOrigin::new(p2.into(), p1.into(), length)
                 } else {
-                    // There is at least one of `left`, `right`, `top`, or `bottom`
-                    if p1.is_horizontal() && p2.is_vertical() {
-                        TransformOrigin::new(p1.into(), p2.into(), length)
-                    } else if p1.is_vertical() && p2.is_horizontal() {
-                        TransformOrigin::new(p2.into(), p1.into(), length)
-                    } else {
-                        return Err(TransformOriginError::InvalidParameters);
-                    }
+                    // `left right`, `top bottom`, or a length before a horizontal
+                    // keyword, like `10px left`.
+                    return Err(TransformOriginError::InvalidParameters);
                 }
             }
-            _ => unreachable!(),
         };
 
         Ok(result)
@@ -208,6 +218,16 @@ mod tests {
     test!(parse_5, "bottom", TransformOrigin::new(Length::new(50.0, LengthUnit::Percent), Length::new(100.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
     test!(parse_6, "30px", TransformOrigin::new(Length::new(30.0, LengthUnit::Px), Length::new(50.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
 
+    test!(parse_single_1, "-10%", TransformOrigin::new(Length::new(-10.0, LengthUnit::Percent), Length::new(50.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
+    test!(parse_single_2, " 2em ", TransformOrigin::new(Length::new(2.0, LengthUnit::Em), Length::new(50.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
+
+    test!(parse_three_1, "center bottom 10px", TransformOrigin::new(Length::new(50.0, LengthUnit::Percent), Length::new(100.0, LengthUnit::Percent), Length::new(10.0, LengthUnit::Px)));
+    test!(parse_three_2, "10px 20px 5px", TransformOrigin::new(Length::new(10.0, LengthUnit::Px), Length::new(20.0, LengthUnit::Px), Length::new(5.0, LengthUnit::Px)));
+    test!(parse_three_3, "top left -5px", TransformOrigin::new(Length::new(0.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Percent), Length::new(-5.0, LengthUnit::Px)));
+    test!(parse_two_1, "left 10px", TransformOrigin::new(Length::new(0.0, LengthUnit::Percent), Length::new(10.0, LengthUnit::Px), Length::new(0.0, LengthUnit::Px)));
+    test!(parse_two_2, "10px bottom", TransformOrigin::new(Length::new(10.0, LengthUnit::Px), Length::new(100.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
+    test!(parse_spaces_1, "  2em  ", TransformOrigin::new(Length::new(2.0, LengthUnit::Em), Length::new(50.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
+
     test!(parse_7, "center left", TransformOrigin::new(Length::new(0.0, LengthUnit::Percent), Length::new(50.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
     test!(parse_8, "left center", TransformOrigin::new(Length::new(0.0, LengthUnit::Percent), Length::new(50.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
     test!(parse_9, "center bottom", TransformOrigin::new(Length::new(50.0, LengthUnit::Percent), Length::new(100.0, LengthUnit::Percent), Length::new(0.0, LengthUnit::Px)));
@@ -232,4 +252,35 @@ mod tests {
     test_err!(parse_err_3, "center some", "transform origin has invalid parameters");
     test_err!(parse_err_4, "left right", "transform origin has invalid parameters");
     test_err!(parse_err_5, "left top 3%", "z-index cannot be a percentage");
+    test_err!(parse_err_6, " ", "transform origin doesn't have enough parameters");
+    test_err!(parse_err_7, ",left", "transform origin has invalid parameters");
+    test_err!(parse_err_8, "10px left", "transform origin has invalid parameters");
+    test_err!(parse_err_9, "top 10px", "transform origin has invalid parameters");
+    test_err!(parse_err_10, "top bottom", "transform origin has invalid parameters");
+    test_err!(parse_err_11, "left top 3px 4px", "transform origin has invalid parameters");
+    test_err!(parse_err_12, "left top center", "transform origin has invalid parameters");
+
+    #[test]
+    fn display_1() {
+        let v = TransformOrigin::from_str("left top").unwrap();
+        assert_eq!(v.to_string(), "0% 0%");
+        assert_eq!(TransformOrigin::from_str(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn display_2() {
+        let v = TransformOrigin::from_str("center right 3px").unwrap();
+        assert_eq!(v.to_string(), "100% 50% 3px");
+        assert_eq!(TransformOrigin::from_str(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn error_display_1() {
+        assert_eq!(TransformOriginError::MissingParameters.to_string(),
+                   "transform origin doesn't have enough parameters");
+        assert_eq!(TransformOriginError::InvalidParameters.to_string(),
+                   "transform origin has invalid parameters");
+        assert_eq!(TransformOriginError::ZIndexIsPercentage.to_string(),
+                   "z-index cannot be a percentage");
+    }
 }
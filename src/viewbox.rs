@@ -1,7 +1,7 @@
 use crate::Stream;
 
 /// List of possible [`ViewBox`] parsing errors.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum ViewBoxError {
     /// One of the numbers is invalid.
     InvalidNumber,
@@ -34,6 +34,7 @@ impl std::error::Error for ViewBoxError {
 /// [`<viewBox>`]: https://www.w3.org/TR/SVG2/coords.html#ViewBoxAttribute
 #[allow(missing_docs)]
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewBox {
     pub x: f64,
     pub y: f64,
@@ -46,6 +47,28 @@ impl ViewBox {
     pub fn new(x: f64, y: f64, w: f64, h: f64) -> Self {
         ViewBox { x, y, w, h }
     }
+
+    /// Creates a unit `ViewBox`, i.e. `0 0 1 1`.
+    #[inline]
+    pub fn unit() -> Self {
+        ViewBox::new(0.0, 0.0, 1.0, 1.0)
+    }
+}
+
+impl Default for ViewBox {
+    /// Returns a unit `ViewBox`.
+    ///
+    /// A zero-sized `ViewBox` is invalid, therefore the unit one is used instead.
+    #[inline]
+    fn default() -> Self {
+        ViewBox::unit()
+    }
+}
+
+impl std::fmt::Display for ViewBox {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} {} {} {}", self.x, self.y, self.w, self.h)
+    }
 }
 
 impl std::str::FromStr for ViewBox {
@@ -93,6 +116,27 @@ mod tests {
 
     test!(parse_1, "-20 30 100 500", ViewBox::new(-20.0, 30.0, 100.0, 500.0));
 
+    #[test]
+    fn display_1() {
+        let v = ViewBox::from_str("-20 30 100 500").unwrap();
+        assert_eq!(v.to_string(), "-20 30 100 500");
+        assert_eq!(ViewBox::from_str(&v.to_string()).unwrap(), v);
+    }
+
+    #[test]
+    fn display_2() {
+        assert_eq!(ViewBox::new(0.5, 0.0, 10.25, 1.0).to_string(), "0.5 0 10.25 1");
+    }
+
+    #[test]
+    fn unit_1() {
+        let v = ViewBox::unit();
+        assert_eq!(v, ViewBox::new(0.0, 0.0, 1.0, 1.0));
+        assert_eq!(v.w, 1.0);
+        assert_eq!(v.h, 1.0);
+        assert_eq!(ViewBox::default(), v);
+    }
+
     macro_rules! test_err {
         ($name:ident, $text:expr, $result:expr) => (
             #[test]
@@ -109,4 +153,18 @@ mod tests {
     test_err!(parse_err_5, "10 20 -30 0", "viewBox has a negative or zero size");
     test_err!(parse_err_6, "10 20 30 -40", "viewBox has a negative or zero size");
     test_err!(parse_err_7, "10 20 -30 -40", "viewBox has a negative or zero size");
+
+    #[test]
+    fn parse_err_8() {
+        assert_eq!(ViewBox::from_str("10 20 30").unwrap_err(), ViewBoxError::InvalidNumber);
+        assert_eq!(ViewBox::from_str("10 20 30 0").unwrap_err(), ViewBoxError::InvalidSize);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_1() {
+        let v = ViewBox::new(-20.0, 30.0, 100.0, 500.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(serde_json::from_str::<ViewBox>(&json).unwrap(), v);
+    }
 }